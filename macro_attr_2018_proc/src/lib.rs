@@ -0,0 +1,52 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+//! The `#[macro_attr]` attribute front-end for the
+//! [`macro-attr-2018`](https://crates.io/crates/macro-attr-2018) crate.
+//!
+//! Do not depend on this crate directly; enable the `proc-macro` feature of `macro-attr-2018`
+//! and use `macro_attr_2018::attr::macro_attr` instead.
+
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Processes the item it is applied to exactly as if it was wrapped in `macro_attr! { ... }`.
+///
+/// The attribute should be placed before all other attributes of the item,
+/// so that it receives `#[derive(...)]` attributes along with the item.
+/// `#[macro_attr(Name!(...))]` applies the attribute macro `Name!` to the item.
+///
+/// The expansion refers to `::macro_attr_2018::macro_attr!` by its absolute path,
+/// so `macro-attr-2018` must be a direct dependency of the calling crate, under its own name.
+#[proc_macro_attribute]
+pub fn macro_attr(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut input = TokenStream::new();
+    if !attr.is_empty() {
+        input.extend([
+            TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+            TokenTree::Group(Group::new(Delimiter::Bracket, attr)),
+        ]);
+    }
+    input.extend(item);
+    let mut output = TokenStream::new();
+    for segment in ["macro_attr_2018", "macro_attr"] {
+        output.extend([
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Ident(Ident::new(segment, Span::call_site())),
+        ]);
+    }
+    output.extend([
+        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+        TokenTree::Group(Group::new(Delimiter::Brace, input)),
+    ]);
+    output
+}
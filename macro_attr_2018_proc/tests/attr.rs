@@ -0,0 +1,69 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::attr::macro_attr;
+
+trait TypeName {
+    fn type_name() -> &'static str;
+}
+
+macro_rules! TypeName {
+    (($($prefix:literal)?) $vis:vis $kw:ident $name:ident $($tail:tt)+) => {
+        impl TypeName for $name {
+            fn type_name() -> &'static str { concat!($($prefix,)? stringify!($name)) }
+        }
+    };
+}
+
+macro_rules! Fields {
+    (@attrs [$(#[$($attr:tt)*])*] () $vis:vis struct $name:ident { $($(#[$($field_attr:tt)*])* $field:ident : $ty:ty),* $(,)? }) => {
+        impl $name {
+            pub const ATTRS: &'static [&'static str] = &[$(stringify!($($attr)*)),*];
+            pub const FIELDS: &'static [&'static str] = &[$(stringify!($field)),*];
+        }
+    };
+}
+
+macro_rules! Wrapped {
+    (($inner:ty) $(#[$attr:meta])* $vis:vis struct $name:ident;) => {
+        $(#[$attr])*
+        $vis struct $name(pub $inner);
+    };
+}
+
+#[macro_attr]
+#[derive(Debug, Clone, TypeName!, @attrs Fields!)]
+#[allow(dead_code)]
+pub struct Point {
+    #[macro_attr(skip)]
+    x: i32,
+    #[macro_attr(rename)]
+    y: i32,
+}
+
+#[macro_attr]
+#[derive(PartialEq, TypeName!("enum "))]
+enum Kind { A, B }
+
+#[macro_attr(Wrapped!(u8))]
+#[derive(Debug, PartialEq)]
+struct Byte;
+
+#[test]
+fn test_attr_front_end() {
+    assert_eq!(Point::type_name(), "Point");
+    assert_eq!(Point::ATTRS, &["allow(dead_code)"]);
+    assert_eq!(Point::FIELDS, &["x", "y"]);
+    let p = Point { x: 1, y: 2 };
+    assert_eq!(format!("{:?}", p.clone()), "Point { x: 1, y: 2 }");
+    assert_eq!(Kind::type_name(), "enum Kind");
+    assert!(Kind::A != Kind::B);
+    assert_eq!(Byte(1), Byte(1));
+}
@@ -18,8 +18,8 @@
 //! This crate provides the `macro_attr!` macro that enables the use of custom,
 //! macro-based attributes and derivations.
 //!
-//! The `macro_attr!` macro should be used to wrap an entire *single* item
-//! (`enum`, `struct`, *etc.*) declaration, including its attributes (both `derive` and others).
+//! The `macro_attr!` macro should be used to wrap entire item
+//! (`enum`, `struct`, *etc.*) declarations, including their attributes (both `derive` and others).
 //! All attributes and derivations which whose names end with `!` will be assumed
 //! to be implemented by macros, and treated accordingly.
 //!
@@ -76,14 +76,64 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub extern crate alloc;
+
 #[doc=include_str!("../README.md")]
 type _DocTestReadme = ();
 
+/// The `#[macro_attr]` attribute, available with the `proc-macro` feature.
+///
+/// Wrapping an item in [`macro_attr!`] hides it from `rustfmt` and makes IDEs treat it as a macro
+/// argument. The `#[macro_attr]` attribute processes the item it is applied to exactly as `macro_attr!`
+/// does, with the same invocation protocol for macro derivations, so it can be used instead:
+///
+/// ```rust
+/// use macro_attr_2018::attr::macro_attr;
+///
+/// trait TypeName {
+///     fn type_name() -> &'static str;
+/// }
+///
+/// macro_rules! TypeName {
+///     (() $vis:vis struct $name:ident $($tail:tt)+) => {
+///         impl TypeName for $name {
+///             fn type_name() -> &'static str { stringify!($name) }
+///         }
+///     };
+/// }
+///
+/// #[macro_attr]
+/// #[derive(Debug, TypeName!)]
+/// struct Foo;
+///
+/// # fn main() {
+/// assert_eq!(Foo::type_name(), "Foo");
+/// # }
+/// ```
+///
+/// The attribute should precede all other attributes of the item, so that it receives them,
+/// `#[derive(...)]` included. Since the item has to be valid Rust syntax, attributes ending with `!`
+/// cannot be written on it: an attribute macro is given as the argument, `#[macro_attr(Name!(...))]`,
+/// and only `#[macro_attr(...)]` helper attributes can be put on fields and variants.
+///
+/// The attribute expands to a call to `::macro_attr_2018::macro_attr!`, so the crate has to be available
+/// under its own name: it does not work if `macro-attr-2018` is renamed in `Cargo.toml`
+/// or only reachable through a re-export from another crate.
+#[cfg(feature = "proc-macro")]
+pub mod attr {
+    pub use macro_attr_2018_proc::macro_attr;
+}
+
 /// When given an item definition, including its attributes, this macro parses said attributes
 /// and dispatches any derivations suffixed with `!` to user-defined macros.
 ///
 /// This allows multiple macros to process the same item.
 ///
+/// Any number of items may be wrapped in a single `macro_attr!` invocation; each of them is processed
+/// independently, and items without attributes are passed through untouched.
+///
 /// Given the following input:
 ///
 /// ```ignore
@@ -103,200 +153,4086 @@ type _DocTestReadme = ();
 ///
 /// Note that macro derives may be mixed with regular derives,
 /// or put in their own `#[derive(...)]` attribute.
-/// Also note that macro derive invocations are *not* passed the other attributes on the item;
+/// Also note that macro derive invocations are *not* passed the other attributes on the item unless `@attrs` is given;
 /// input will consist of the arguments provided to the derivation (*i.e.* `(args...)`
 /// in this example), the item's visibility (if any), and the item definition itself.
+/// The visibility is passed on exactly as written for every item kind, so it can be matched
+/// either as `$vis:vis` or token by token (`pub`, `pub(crate)`, `pub(in path)`, and so on).
+///
+/// A macro derive can be given additional input by prefixing it with options. With `@attrs`,
+/// the derivation also receives the item's attributes (except `derive`s and attribute macros)
+/// as a bracketed group in front of its arguments:
+///
+/// ```ignore
+/// #[derive(@attrs Name!(args...))]
+/// #[repr(u8)]
+/// enum Foo { A }
+/// ```
+///
+/// will invoke
+///
+/// ```ignore
+/// Name!(@attrs [#[repr(u8)]] (args...) enum Foo { A });
+/// ```
+///
+/// With `@derives`, the derivation is told which other derives are present on the item,
+/// as a list of regular derives followed by a list of macro derives:
+///
+/// ```ignore
+/// #[derive(Clone, Copy, @derives Name!, Another!)]
+/// struct Foo;
+/// ```
+///
+/// will invoke
+///
+/// ```ignore
+/// Name!(@derives [Clone, Copy] [Name!, Another!] () struct Foo;);
+/// ```
+///
+/// A derive given under `cfg_attr` or with `if` is listed with its predicate, *e.g.* `#[cfg(feature = "std")] Copy`,
+/// so that the derivation can put the same condition on the code that relies on it.
+///
+/// With `@kind`, the derivation receives the kind of the item as a single keyword, with the visibility
+/// and qualifiers such as `const`, `unsafe`, `async` and `extern "ABI"` stripped away, so that it
+/// can tell a function from an `impl` block without parsing the item:
+///
+/// ```ignore
+/// #[derive(@kind Name!)]
+/// pub const unsafe extern "C" fn foo() { }
+/// ```
+///
+/// will invoke
+///
+/// ```ignore
+/// Name!(@kind [fn] () pub const unsafe extern "C" fn foo() { });
+/// ```
+///
+/// The kind is one of `struct`, `enum`, `union`, `trait`, `fn`, `impl`, `mod`, `type`, `const`, `static`,
+/// `use`, `extern` (for `extern` blocks), `extern_crate`, `macro_rules`, or `item` for anything else.
+///
+/// With `@recurse`, items emitted by the derivation are processed by `macro_attr!` again,
+/// so they may use macro derives themselves. The derivation receives a macro path and a state
+/// in front of its arguments, and should wrap its output into a call to that macro, passing the state first:
+///
+/// ```ignore
+/// macro_rules! Builder {
+///     (@recurse $macro_attr:path { $($state:tt)* } () $vis:vis struct $name:ident { ... }) => {
+///         $macro_attr! {
+///             $($state)*
+///             #[derive(Default, Name!)]
+///             $vis struct FooBuilder { ... }
+///         }
+///     };
+/// }
+/// ```
+///
+/// Nested expansion is limited to 4 levels by default; the limit may be set with `@recurse(N)`, where `N` is
+/// from 1 to 8, and is taken from the outermost recursive derivation. Exceeding it is a compile-time error.
+///
+/// With `@parsed`, a `struct`, `enum`, `union` or `trait` item is also passed in a normalised form,
+/// so the derivation does not need to parse it on its own:
+///
+/// ```ignore
+/// @parsed {
+///     kind: struct,
+///     vis: [pub(crate)],
+///     name: Foo,
+///     generics: ['a, T: Clone],
+///     where: [T: Copy],
+///     fields: named [
+///         { attrs: [#[doc = "..."]], vis: [pub], name: a, ty: [&'a T] }
+///         { attrs: [], vis: [], name: b, ty: [u8] }
+///     ]
+/// }
+/// ```
+///
+/// Fields of a tuple struct are given as `fields: tuple [{ attrs: [...], vis: [...], ty: [...] } ...]`,
+/// and a unit struct has `fields: unit []`. An `enum` has `variants` instead of `fields`:
+///
+/// ```ignore
+/// variants: [
+///     { attrs: [], name: A, fields: unit [], discriminant: [1] }
+///     { attrs: [], name: B, fields: tuple [{ attrs: [], vis: [], ty: [u8] }], discriminant: [] }
+/// ]
+/// ```
+///
+/// A `trait` has its safety, supertraits and associated items, which are types, constants and methods:
+///
+/// ```ignore
+/// @parsed {
+///     kind: trait,
+///     vis: [pub],
+///     safety: [],
+///     name: Shape,
+///     generics: [A],
+///     where: [],
+///     supertraits: [Clone],
+///     items: [
+///         { attrs: [], kind: type, name: Unit, generics: [], bounds: [Copy], where: [] }
+///         { attrs: [], kind: const, name: SIDES, ty: [u32], default: [] }
+///         {
+///             attrs: [], kind: fn, safety: [], name: scale, generics: [T: Into<f64>],
+///             receiver: mut [&mut self], args: [{ pat: [by], ty: [T] }], ret: [], where: [], default: []
+///         }
+///     ]
+/// }
+/// ```
+///
+/// The receiver is one of `ref`, `mut`, `value` (for `self` and `mut self`), `typed` (for `self: Type`)
+/// or `none`, followed by its tokens. Each argument has its pattern in `pat`, such as `[by]`, `[mut by]`
+/// or `[(a, b)]`. A provided method has its body in `default`. A generic associated type has its generic
+/// parameters and where predicates in `generics` and `where`.
+///
+/// The generic parameters may be passed to [`split_generics!`] to write an `impl` for the item.
+/// The `@parsed` section always follows the sections of the other options, whatever the order of the options.
+///
+/// With `@bundle`, the derive refers to a bundle declared with [`macro_attr_bundle!`], and is replaced with
+/// the derives listed in the bundle: `#[derive(@bundle IdEnum!)]`. A bundle takes no arguments and
+/// cannot be combined with other options.
+///
+/// With `@v2`, the derivation is invoked with the second version of the invocation protocol,
+/// where everything but the item is passed in a single self-describing section:
+///
+/// ```ignore
+/// Name! {
+///     @macro_attr v2 {
+///         kind: struct,
+///         args: (args...),
+///         attrs: [#[repr(C)]],
+///         derives: [Clone, Copy],
+///         macro_derives: [Name!, Another!],
+///         recurse: [],
+///         parsed: [],
+///     }
+///     struct Foo;
+/// }
+/// ```
+///
+/// All keys are always present, in this order. `kind`, `attrs` and `derives` are what `@kind`, `@attrs` and
+/// `@derives` would pass (so `@v2` cannot be combined with them),
+/// and `recurse` and `parsed` hold the `@recurse` and `@parsed` sections if these options are given, *e.g.*
+/// `recurse: [$macro_attr:path { $($state:tt)* }]`. Derivations which are not invoked with `@v2` keep receiving
+/// the original form, unless they declare that they use the second version: `macro_attr!` defines
+/// `__macro_attr_v2!` before invoking a derivation, and a derivation whose last rule passes any other input
+/// to it is then invoked again as with `@v2`, so that a plain `#[derive(Name!)]` is enough:
+///
+/// ```ignore
+/// macro_rules! Name {
+///     (@macro_attr v2 { $($keys:tt)* } $($item:tt)*) => { /* ... */ };
+///     ($($v1:tt)*) => { __macro_attr_v2! { } };
+/// }
+/// ```
 ///
 /// A macro derivation invoked *without* arguments will be treated as though
 /// it was invoked with empty parentheses.  *i.e.* `#[derive(Name!)]` is equivalent to `#[derive(Name!())]`.
+/// Arguments may also be given in brackets or braces, `#[derive(Name![args...])]` or `#[derive(Name!{args...})]`;
+/// they are passed to the derivation in parentheses, exactly as `Name!(args...)` would be.
 ///
 /// A derivation macro may expand to any number of new items derived from the provided input.
+///
+/// Fields and variants of a `struct`, `enum` or `union` with macro derives may carry helper attributes
+/// for them: either `#[macro_attr(...)]`, or `#[Name!(...)]` (*i.e.* any attribute whose name ends with `!`).
+/// Helper attributes are removed from the emitted item, but are kept in the copy passed to derivations:
+///
+/// ```ignore
+/// #[derive(Getters!)]
+/// struct Foo {
+///     #[Getters!(skip)]
+///     a: u8,
+/// }
+/// ```
+///
+/// Fields and variants are processed in bulk, so large items fit into the default `recursion_limit`.
+/// Fields and variants without attributes, or with `name = value` attributes only (such as doc comments),
+/// are copied many at a time. Any other attribute on a field or variant, helper attributes included,
+/// takes an expansion step of its own, so the expansion depth grows with the number of such attributes
+/// and with the number of macro derives.
+/// Associated items of a `trait` are parsed one by one, so a trait with many generic methods
+/// passed to a derivation with `@parsed` may need a higher limit.
+/// The items of one `macro_attr!` block are split before expansion, so each item starts
+/// at the same depth however many items precede it. A block with an item whose header holds
+/// generics, a path or a `where` clause is split one item at a time instead, and its depth
+/// grows with the number of items.
+///
+/// Macro derivations may be referred to by path, *e.g.* `#[derive(other_crate::Name!)]`,
+/// `#[derive(::other_crate::Name!)]` or `#[derive($crate::Name!)]` (the latter is useful when
+/// `macro_attr!` invocation is generated by another macro). The macro is invoked through that exact path.
+/// Regular derives may be paths as well (`#[derive(serde::Serialize, ::core::hash::Hash)]`),
+/// they are passed through unchanged.
+///
+/// Derives may also be given conditionally, with `#[cfg_attr(predicate, derive(...))]`.
+/// Regular derives stay inside `cfg_attr`, and macro derive invocations are wrapped in `#[cfg(predicate)]`:
+///
+/// ```ignore
+/// #[cfg_attr(feature = "std", derive(Debug, Name!))]
+/// struct Foo;
+/// ```
+///
+/// expands to the equivalent of:
+///
+/// ```ignore
+/// #[cfg_attr(feature = "std", derive(Debug))]
+/// struct Foo;
+///
+/// #[cfg(feature = "std")]
+/// Name!(() struct Foo;);
+/// ```
+///
+/// A single derive may be made conditional inline, with `if predicate` after it:
+/// `#[derive(Clone, Debug if feature = "std", Name! if feature = "std")]` is the same as the above
+/// plus `#[derive(Clone)]`. Inside `cfg_attr`, both predicates must hold.
+///
+/// The emitted item keeps its attributes in the order they were written. Each `derive` (or `cfg_attr` with `derive`)
+/// attribute is kept as a single attribute listing its regular derives, with macro derives taken out;
+/// a derive bundle or an inline condition splits the attribute at its position.
+///
+/// Attributes whose names end with `!` are dispatched to user-defined macros as well,
+/// but, unlike derivations, such an attribute macro *replaces* the item. Given
+///
+/// ```ignore
+/// #[derive(Clone, Name!)]
+/// #[Transform!(args...)]
+/// #[repr(C)]
+/// struct Foo;
+/// ```
+///
+/// `macro_attr!` will expand to the equivalent of:
+///
+/// ```ignore
+/// Transform!((args...) #[derive(Clone)] #[repr(C)] struct Foo;);
+///
+/// Name!(() struct Foo;);
+/// ```
+///
+/// The attribute macro receives its arguments, the remaining attributes (with macro derives
+/// already taken out) and the item definition, and may expand to any number of items.
+/// Macro derivations are still invoked with the item as written.
+///
+/// Such an attribute macro must be the last one of the item. Attribute macros written with `@chain`,
+/// `#[@chain Name!(...)]`, are applied one by one, in the order they are written,
+/// as `rustc` applies attribute proc-macros. Each of them receives `@chain`, a macro path and a state
+/// in front of its arguments, followed by all the other attributes of the item in the order they were written
+/// (the later attribute macros and macro derives included) and the item definition.
+/// It should wrap the transformed item into a call to that macro, passing the state first:
+///
+/// ```ignore
+/// macro_rules! AddField {
+///     (
+///         @chain $macro_attr:path { $($state:tt)* } ($new:ident: $new_ty:ty)
+///         $(#[$($attrs:tt)*])* $vis:vis struct $name:ident { $($field:ident: $ty:ty),* $(,)? }
+///     ) => {
+///         $macro_attr! {
+///             $($state)*
+///             $(#[$($attrs)*])*
+///             $vis struct $name { $($field: $ty,)* $new: $new_ty }
+///         }
+///     };
+/// }
+/// ```
+///
+/// The remaining attribute macros are then dispatched on the transformed item in the same way,
+/// and macro derivations are invoked on the item that the last attribute macro receives,
+/// or on the final item if every attribute macro is written with `@chain`. Given
+///
+/// ```ignore
+/// #[derive(Clone, Name!)]
+/// #[@chain AddField!(b: u16)]
+/// struct Foo { a: u8 }
+/// ```
+///
+/// `AddField!` receives `@chain`, `#[derive(Clone, Name!)]` and the item, and `Name!` is then invoked
+/// with `struct Foo { a: u8, b: u16 }`.
 #[macro_export]
 macro_rules! macro_attr {
     (
-        $(#[$($attrs:tt)+])*
-        $(pub $(($($vis:tt)+))?)? enum $($it:tt)+
+        $($items:tt)*
     ) => {
         $crate::macro_attr_impl! {
-            @split_attrs [$(pub $(($($vis)+))?)? enum $($it)+]
-            [] []
-            [$([$($attrs)+])*]
+            @items () $($items)*
         }
     };
+}
+
+/// Runs macro derivations against a mirror definition of an item, without emitting the item itself.
+///
+/// This is useful for deriving with macros for a type defined elsewhere, *e.g.* in another crate:
+/// the definition is copied into `macro_attr_remote!`, and only the output of macro derives is emitted,
+/// while the item, its regular derives and its attribute macros are dropped.
+///
+/// ```rust
+/// use macro_attr_2018::macro_attr_remote;
+/// use std::cmp::Ordering;
+///
+/// trait TypeName {
+///     fn type_name() -> &'static str;
+/// }
+///
+/// macro_rules! TypeName {
+///     (() $vis:vis enum $name:ident $($tail:tt)+) => {
+///         impl TypeName for $name {
+///             fn type_name() -> &'static str { stringify!($name) }
+///         }
+///     };
+/// }
+///
+/// macro_attr_remote! {
+///     #[derive(Clone, Copy, TypeName!)]
+///     pub enum Ordering { Less = -1, Equal = 0, Greater = 1 }
+/// }
+///
+/// # fn main() {
+/// assert_eq!(Ordering::type_name(), "Ordering");
+/// # }
+/// ```
+///
+/// Macro derives are invoked exactly as by [`macro_attr!`], with the same options and helper attributes.
+/// Items produced by `@recurse` derivations are emitted as usual.
+#[macro_export]
+macro_rules! macro_attr_remote {
     (
-        $(#[$($attrs:tt)+])*
-        $(pub $(($($vis:tt)+))?)? struct $($it:tt)+
+        $($items:tt)*
     ) => {
         $crate::macro_attr_impl! {
-            @split_attrs [$(pub $(($($vis)+))?)? struct $($it)+]
-            [] []
-            [$([$($attrs)+])*]
+            @items (remote) $($items)*
         }
     };
+}
+
+/// Splits generic parameters of an item into the parts needed to write an `impl` for it,
+/// and passes them to a continuation macro.
+///
+/// This is intended for use in macro derivations. Given the generic parameters (the contents
+/// of `<...>`, as passed by the `@parsed` option of [`macro_attr!`]) and, optionally, the where predicates,
+///
+/// ```ignore
+/// split_generics! { Callback! { args... } ['a, T: ?Sized + Clone = u8, const N: usize = 3] [T: Copy] }
+/// ```
+///
+/// invokes
+///
+/// ```ignore
+/// Callback! { args... ['a, T: ?Sized + Clone, const N: usize,] ['a, T, N,] [T: Copy] }
+/// ```
+///
+/// so the continuation may emit `impl<$($impl)*> Trait for Name<$($ty)*> where $($where)* { ... }`.
+/// Defaults are removed from the impl generics, while bounds are kept.
+#[macro_export]
+macro_rules! split_generics {
     (
-        $(#[$($attrs:tt)+])*
-        $(pub $(($($vis:tt)+))?)? trait $($it:tt)+
+        $($callback_root:ident)? $(:: $callback_segment:ident)* ! { $($callback_args:tt)* }
+        [$($params:tt)*] $([$($where:tt)*])?
     ) => {
         $crate::macro_attr_impl! {
-            @split_attrs [$(pub ($($vis)+))? trait $($it)+]
-            [] []
-            [$([$($attrs)+])*]
+            @split_generics [[$($callback_root)? $(:: $callback_segment)*] { $($callback_args)* } [$($($where)*)?]]
+            [] [] [] [] start $($params)*
         }
     };
+}
+
+/// Declares derive bundles: named lists of derives which may be used in [`macro_attr!`]
+/// instead of repeating the whole list on every item.
+///
+/// ```rust
+/// use macro_attr_2018::{macro_attr, macro_attr_bundle};
+///
+/// trait TypeName {
+///     fn type_name() -> &'static str;
+/// }
+///
+/// macro_rules! TypeName {
+///     (() $vis:vis enum $name:ident $($tail:tt)+) => {
+///         impl TypeName for $name {
+///             fn type_name() -> &'static str { stringify!($name) }
+///         }
+///     };
+/// }
+///
+/// macro_attr_bundle! {
+///     Ordinal = PartialEq, Eq, PartialOrd, Ord;
+///     IdEnum = Debug, Clone, Copy, Hash, @bundle Ordinal!, TypeName!;
+/// }
+///
+/// macro_attr! {
+///     #[derive(@bundle IdEnum!)]
+///     enum Color { Red, Green }
+/// }
+///
+/// # fn main() {
+/// assert!(Color::Red < Color::Green);
+/// assert_eq!(Color::type_name(), "Color");
+/// # }
+/// ```
+///
+/// A bundle is used as a macro derive marked with the `@bundle` option, and expands to the derives
+/// it lists, in place. Bundles may contain regular and macro derives with their arguments and options,
+/// and other bundles. A bundle used inside `#[cfg_attr(predicate, derive(...))]` makes all its derives
+/// conditional.
+///
+/// A bundle is declared as a macro with the given name, so it follows the usual scoping rules of
+/// `macro_rules!` macros. It may be given a visibility: `pub` bundles are exported from the crate
+/// with `#[macro_export]`, and `pub(...)` bundles are re-exported with `pub(...) use`.
+#[macro_export]
+macro_rules! macro_attr_bundle {
     (
-        $(#[$($attrs:tt)+])*
-        $vis:vis $keyword:ident $($it:tt)+
+        $($bundles:tt)*
     ) => {
         $crate::macro_attr_impl! {
-            @split_attrs [$vis $keyword $($it)+]
-            [] []
-            [$([$($attrs)+])*]
+            @bundles [] $($bundles)*
         }
     };
 }
 
-#[doc(hidden)]
+/// Declares parsers for `key = value` argument lists of macro derivations.
+///
+/// A parser is declared with its keys and their default values:
+///
+/// ```ignore
+/// macro_attr_args! {
+///     BuilderArgs { name = [Builder], vis = [], debug = [false] }
+/// }
+/// ```
+///
+/// and is invoked with a continuation macro and the arguments to parse:
+///
+/// ```ignore
+/// BuilderArgs! { Callback! { args... } (name = FooBuilder, vis = pub(crate), debug) }
+/// ```
+///
+/// which invokes
+///
+/// ```ignore
+/// Callback! { args... { name: [FooBuilder], vis: [pub(crate)], debug: [true] } }
+/// ```
+///
+/// Arguments are written as `key = value` (the value extends up to the next comma outside of brackets,
+/// `<` and `>` included, so that it may be a type such as `HashMap<K, V>`; if these do not pair up,
+/// as in `1 << 2`, up to the next comma), `key(value...)`
+/// (the value may contain commas), or just `key`, which is the same as `key = true`. The keys are passed
+/// in the order of the declaration, with the defaults for missing ones. An unknown key is a compile-time error.
+/// If a key is given more than once, the last value is used.
+///
+/// ```rust
+/// use macro_attr_2018::{macro_attr, macro_attr_args};
+///
+/// macro_attr_args! {
+///     BuilderArgs { name = [Builder], vis = [], debug = [false] }
+/// }
+///
+/// macro_rules! Builder {
+///     (($($args:tt)*) $vis:vis struct $name:ident { $($field:ident: $ty:ty),* $(,)? }) => {
+///         BuilderArgs! { Builder! { @impl $name [$($field: $ty),*] } ($($args)*) }
+///     };
+///     (
+///         @impl $name:ident [$($field:ident: $ty:ty),*]
+///         { name: [$builder:ident], vis: [$builder_vis:vis], debug: [$debug:literal] }
+///     ) => {
+///         #[derive(Default)]
+///         $builder_vis struct $builder { $($field: Option<$ty>),* }
+///
+///         impl $builder {
+///             const DEBUG: bool = $debug;
+///
+///             $(
+///                 $builder_vis fn $field(mut self, $field: $ty) -> Self {
+///                     self.$field = Some($field);
+///                     self
+///                 }
+///             )*
+///
+///             $builder_vis fn build(self) -> Option<$name> {
+///                 Some($name { $($field: self.$field?),* })
+///             }
+///         }
+///     };
+/// }
+///
+/// macro_attr! {
+///     #[derive(Builder!(name = PointBuilder, vis = pub(crate), debug))]
+///     struct Point { x: i32, y: i32 }
+/// }
+///
+/// # fn main() {
+/// let point = PointBuilder::default().x(1).y(2).build().unwrap();
+/// assert_eq!((point.x, point.y), (1, 2));
+/// assert!(PointBuilder::DEBUG);
+/// # }
+/// ```
+///
+/// A parser is declared as a macro with the given name, and invokes itself by that name, so it should be in scope
+/// wherever it is used. As with [`macro_attr_bundle!`], a parser may be given a visibility.
 #[macro_export]
-macro_rules! macro_attr_impl {
+macro_rules! macro_attr_args {
     (
-        @split_attrs [$($it:tt)+]
-        [$($derive_attrs:tt)*] [$([$other_attrs:meta])*]
-        [[derive($($derive_attr:tt)+)] $([$($attrs:tt)+])*]
+        $($parsers:tt)*
     ) => {
         $crate::macro_attr_impl! {
-            @split_attrs [$($it)+]
-            [$($derive_attrs)* [$($derive_attr)+]]
-            [$([$other_attrs])*]
-            [$([$($attrs)+])*]
+            @args_defs [] $($parsers)*
         }
     };
+}
+
+/// A macro derive for traits, which implements the trait for references and smart pointers
+/// to its implementors by forwarding every associated item to the implementation of `T`.
+///
+/// ```rust
+/// use macro_attr_2018::{macro_attr, Forward};
+///
+/// macro_attr! {
+///     #[derive(Forward!)]
+///     trait Shape {
+///         const SIDES: u32;
+///         fn area(&self) -> f64;
+///         fn scale(&mut self, factor: f64);
+///     }
+/// }
+///
+/// struct Square(f64);
+///
+/// impl Shape for Square {
+///     const SIDES: u32 = 4;
+///     fn area(&self) -> f64 { self.0 * self.0 }
+///     fn scale(&mut self, factor: f64) { self.0 *= factor; }
+/// }
+///
+/// fn scaled_area(mut shape: impl Shape, factor: f64) -> f64 {
+///     shape.scale(factor);
+///     shape.area()
+/// }
+///
+/// # fn main() {
+/// let mut square = Square(1.0);
+/// assert_eq!(scaled_area(&mut square, 2.0), 4.0);
+/// assert_eq!(<&mut Square as Shape>::SIDES, 4);
+/// # }
+/// ```
+///
+/// The impls to generate may be listed as arguments: `#[derive(Forward!(&, &mut, Box, Rc, Arc))]`.
+/// Without arguments, `Forward!` picks all the targets the receivers of the trait methods allow:
+/// `&T` needs all methods to take `&self`, `&mut T` also allows `&mut self`, and a method taking `self`
+/// by value leaves `Box<T>` only. `Box`, `Rc` and `Arc` require the `alloc` feature of this crate.
+///
+/// Associated types and consts are taken from `T`, and methods call the method of `T` with the same name.
+/// Methods with a default body and a `where Self: Sized` bound are not forwarded, and such methods without
+/// a default body restrict the impls to `T: Sized`. Receivers such as
+/// `self: Box<Self>` and signatures mentioning `Self` in other places cannot be forwarded.
+/// Supertraits are not forwarded: `&T` has to implement them on its own.
+///
+/// `Forward!` parses the trait with the `@parsed` option of [`macro_attr!`], so it may be given either
+/// as `Forward!` or `@parsed Forward!`.
+#[macro_export]
+macro_rules! Forward {
     (
-        @split_attrs [$($it:tt)+]
-        [$($derive_attrs:tt)*] [$([$other_attrs:meta])*]
-        [[$attr:meta] $([$($attrs:tt)+])*]
+        @parsed { $($parsed:tt)* } ($($targets:tt)*) $($item:tt)*
     ) => {
         $crate::macro_attr_impl! {
-            @split_attrs [$($it)+]
-            [$($derive_attrs)*]
-            [$([$other_attrs])* [$attr]]
-            [$([$($attrs)+])*]
+            @forward [$($targets)*] $($parsed)*
         }
     };
     (
-        @split_attrs [$($it:tt)+]
-        [$($derive_attrs:tt)*] [$([$other_attrs:meta])*]
-        []
+        ($($targets:tt)*) $($item:tt)+
     ) => {
         $crate::macro_attr_impl! {
-            @split_derive_attrs [$($it)+] [$([$other_attrs])*]
-            [] []
-            [$($derive_attrs)*]
+            @parse_item [() [] [$crate::Forward] [] [] [($($targets)*) [$($item)+]] [] [[] []]] $($item)+
         }
     };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! macro_attr_impl {
+    (
+        @items $ctx:tt
+    ) => {
+    };
     (
-        @split_derive_attrs [$($it:tt)+] [$([$other_attrs:meta])*]
-        [$($macro_derives:tt)*] [$($std_derives:tt)*]
-        [
-            [$macro_derive:ident ! $(($($macro_derive_args:tt)*))? $(, $($other_inner_derives:tt)*)?]
-            $([$($other_derives:tt)*])*
-        ]
+        @items $ctx:tt
+        $(
+            $(#[$($first_attrs:tt)*])*
+            $($first_word:ident $(($($first_word_args:tt)*))?)+
+            $(-> $first_ret:ty)?
+            $(
+                { $($body:tt)* }
+                $(
+                    $(#[$($attrs:tt)*])*
+                    $($word:ident $(($($word_args:tt)*))?)+
+                    $(-> $ret:ty)?
+                )?
+            )*
+            $(: $ty:ty = $value:expr)?
+        );*
+        $(; $(@ $semi:tt)?)?
     ) => {
         $crate::macro_attr_impl! {
-            @split_derive_attrs [$($it)+] [$([$other_attrs])*]
-            [
-                $($macro_derives)*
-                [$macro_derive ( $($($macro_derive_args)*)? )]
-            ]
-            [
-                $($std_derives)*
-            ]
-            [
-                $([$($other_inner_derives)*])?
-                $([$($other_derives)*])*
-            ]
+            @split_items $ctx
+            $(
+                [$(#[$($first_attrs)*])* $($first_word $(($($first_word_args)*))?)+ $(-> $first_ret)?]
+                $(
+                    { $($body)* }
+                    $([$(#[$($attrs)*])* $($word $(($($word_args)*))?)+ $(-> $ret)?])?
+                )*
+                $(= [$ty] [$value])?
+            );*
+            $(; $(@ $semi)?)?
         }
     };
     (
-        @split_derive_attrs [$($it:tt)+] [$([$other_attrs:meta])*]
-        [$($macro_derives:tt)*] [$($std_derives:tt)*]
-        [
-            [$std_derive:ident $(($($std_derive_args:tt)*))? $(, $($other_inner_derives:tt)*)?]
-            $([$($other_derives:tt)*])*
-        ]
+        @items $ctx:tt
+        $(#[$($attrs:tt)*])*
+        $($word:ident $(($($word_args:tt)*))?)+
+        $(-> $ret:ty)?
+        { $($body:tt)* }
+        $($input:tt)+
     ) => {
         $crate::macro_attr_impl! {
-            @split_derive_attrs [$($it)+] [$([$other_attrs])*]
-            [
-                $($macro_derives)*
-            ]
-            [
-                $($std_derives)*
-                #[derive($std_derive $(($($std_derive_args)*))?)]
-            ]
-            [
-                $([$($other_inner_derives)*])?
-                $([$($other_derives)*])*
-            ]
+            @emit_item $ctx [$(#[$($attrs)*])* $($word $(($($word_args)*))?)+ $(-> $ret)? { $($body)* }]
+        }
+        $crate::macro_attr_impl! {
+            @items $ctx $($input)+
         }
     };
     (
-        @split_derive_attrs [$($it:tt)+] [$([$other_attrs:meta])*]
-        [$($macro_derives:tt)*] [$($std_derives:tt)*]
-        [
-            []
-            $([$($other_derives:tt)*])*
-        ]
+        @items $ctx:tt
+        $(#[$($attrs:tt)*])*
+        $($word:ident $(($($word_args:tt)*))?)+
+        $(: $ty:ty = $value:expr)?
+        ;
+        $($input:tt)+
     ) => {
         $crate::macro_attr_impl! {
-            @split_derive_attrs [$($it)+] [$([$other_attrs])*]
-            [
-                $($macro_derives)*
-            ]
-            [
-                $($std_derives)*
-            ]
-            [
-                $([$($other_derives)*])*
-            ]
+            @emit_item $ctx [$(#[$($attrs)*])* $($word $(($($word_args)*))?)+ $(: $ty = $value)? ;]
+        }
+        $crate::macro_attr_impl! {
+            @items $ctx $($input)+
         }
     };
     (
-        @split_derive_attrs [$($it:tt)+] [$([$other_attrs:meta])*]
-        [$([$macro_derive:ident ( $($macro_derive_args:tt)* )])*]
-        [$($std_derives:tt)*]
-        []
+        @items $ctx:tt $($input:tt)+
     ) => {
         $crate::macro_attr_impl! {
-            @as_item
-            $($std_derives)*
-            $(#[$other_attrs])*
-            $($it)+
+            @scan_item $ctx [] head $($input)+
         }
+    };
+    (
+        @split_items $ctx:tt
+        $(
+            [$($item:tt)*]
+            $({ $($body:tt)* })?
+            $(= [$($ty:tt)*] [$($value:tt)*])?
+            $(; $(@ $semi:tt)?)?
+        )*
+    ) => {
+        $(
+            $crate::macro_attr_impl! {
+                @emit_item $ctx [$($item)* $({ $($body)* })? $(: $($ty)* = $($value)*)? $(; $($semi)?)?]
+            }
+        )*
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] head
+        # [$($a0:tt)*] # [$($a1:tt)*] # [$($a2:tt)*] # [$($a3:tt)*]
+        # [$($a4:tt)*] # [$($a5:tt)*] # [$($a6:tt)*] # [$($a7:tt)*]
+        $($input:tt)*
+    ) => {
         $crate::macro_attr_impl! {
-            @expand [$($it)+]
-            [$([$macro_derive ( $($macro_derive_args)* )])*]
+            @scan_item $ctx [
+                $($item)*
+                # [$($a0)*] # [$($a1)*] # [$($a2)*] # [$($a3)*]
+                # [$($a4)*] # [$($a5)*] # [$($a6)*] # [$($a7)*]
+            ] head $($input)*
         }
     };
     (
-        @expand [$($it:tt)+]
-        [
-            [$macro_derive:ident ( $($macro_derive_args:tt)* )]
-            $([$other_macro_derives:ident ( $($other_macro_derives_args:tt)* )])*
-        ]
+        @scan_item $ctx:tt [$($item:tt)*] head # [$($attr:tt)*] $($input:tt)*
     ) => {
-        $macro_derive! {
-            ( $($macro_derive_args)* )
-            $($it)+
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* # [$($attr)*]] head $($input)*
         }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] head pub ($($vis:tt)*) $($input:tt)*
+    ) => {
         $crate::macro_attr_impl! {
-            @expand [$($it)+]
-            [$([$other_macro_derives ( $($other_macro_derives_args)* )])*]
+            @scan_item $ctx [$($item)* pub ($($vis)*)] head $($input)*
         }
     };
     (
-        @expand [$($it:tt)+]
-        []
+        @scan_item $ctx:tt [$($item:tt)*] head const $qualifier:ident $($input:tt)*
     ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* const] const_qualifier $qualifier $($input)*
+        }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] const_qualifier fn $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* fn] any $($input)*
+        }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] const_qualifier unsafe $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* unsafe] head $($input)*
+        }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] const_qualifier async $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* async] head $($input)*
+        }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] const_qualifier extern $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* extern] head $($input)*
+        }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] const_qualifier $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)*] semi $($input)*
+        }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] head const $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* const] semi $($input)*
+        }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] head extern crate $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* extern crate] semi $($input)*
+        }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] head $qualifier:ident $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item_keyword $ctx [$($item)*] $qualifier $($input)*
+        }
+    };
+    (
+        @scan_item_keyword $ctx:tt [$($item:tt)*] static $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* static] semi $($input)*
+        }
+    };
+    (
+        @scan_item_keyword $ctx:tt [$($item:tt)*] type $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* type] semi $($input)*
+        }
+    };
+    (
+        @scan_item_keyword $ctx:tt [$($item:tt)*] use $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* use] semi $($input)*
+        }
+    };
+    (
+        @scan_item_keyword $ctx:tt [$($item:tt)*] pub $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* pub] head $($input)*
+        }
+    };
+    (
+        @scan_item_keyword $ctx:tt [$($item:tt)*] unsafe $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* unsafe] head $($input)*
+        }
+    };
+    (
+        @scan_item_keyword $ctx:tt [$($item:tt)*] async $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* async] head $($input)*
+        }
+    };
+    (
+        @scan_item_keyword $ctx:tt [$($item:tt)*] extern $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* extern] head $($input)*
+        }
+    };
+    (
+        @scan_item_keyword $ctx:tt [$($item:tt)*] $keyword:ident $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* $keyword] any $($input)*
+        }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] any << $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_angle $ctx [$($item)* <<] [< <] $($input)*
+        }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] any < $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_angle $ctx [$($item)* <] [<] $($input)*
+        }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] any ; $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @emit_item $ctx [$($item)* ;]
+        }
+        $crate::macro_attr_impl! {
+            @items $ctx $($input)*
+        }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] any { $($body:tt)* } $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @emit_item $ctx [$($item)* { $($body)* }]
+        }
+        $crate::macro_attr_impl! {
+            @items $ctx $($input)*
+        }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] semi ; $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @emit_item $ctx [$($item)* ;]
+        }
+        $crate::macro_attr_impl! {
+            @items $ctx $($input)*
+        }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] head $token:tt $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* $token] any $($input)*
+        }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] $mode:ident $token:tt $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)* $token] $mode $($input)*
+        }
+    };
+    (
+        @scan_item $ctx:tt [$($item:tt)*] $mode:ident
+    ) => {
+        $crate::macro_attr_impl! {
+            @emit_item $ctx [$($item)*]
+        }
+    };
+    (
+        @scan_angle $ctx:tt [$($item:tt)*] [] $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_item $ctx [$($item)*] any $($input)*
+        }
+    };
+    (
+        @scan_angle $ctx:tt [$($item:tt)*] [$($depth:tt)*] << $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_angle $ctx [$($item)* <<] [< < $($depth)*] $($input)*
+        }
+    };
+    (
+        @scan_angle $ctx:tt [$($item:tt)*] [$($depth:tt)*] < $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_angle $ctx [$($item)* <] [< $($depth)*] $($input)*
+        }
+    };
+    (
+        @scan_angle $ctx:tt [$($item:tt)*] [< $($depth:tt)*] >= $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_angle $ctx [$($item)* >] [$($depth)*] = $($input)*
+        }
+    };
+    (
+        @scan_angle $ctx:tt [$($item:tt)*] [< < $($depth:tt)*] >>= $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_angle $ctx [$($item)* >>] [$($depth)*] = $($input)*
+        }
+    };
+    (
+        @scan_angle $ctx:tt [$($item:tt)*] [< $($depth:tt)*] > $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_angle $ctx [$($item)* >] [$($depth)*] $($input)*
+        }
+    };
+    (
+        @scan_angle $ctx:tt [$($item:tt)*] [< < $($depth:tt)*] >> $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_angle $ctx [$($item)* >>] [$($depth)*] $($input)*
+        }
+    };
+    (
+        @scan_angle $ctx:tt [$($item:tt)*] [$($depth:tt)*] $token:tt $($input:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @scan_angle $ctx [$($item)* $token] [$($depth)*] $($input)*
+        }
+    };
+    (
+        @scan_angle $ctx:tt [$($item:tt)*] [$($depth:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @emit_item $ctx [$($item)*]
+        }
+    };
+    (
+        @emit_item $ctx:tt [# $($item:tt)+]
+    ) => {
+        $crate::macro_attr_impl! {
+            @item $ctx # $($item)+
+        }
+    };
+    (
+        @emit_item (remote) [$($item:tt)*]
+    ) => {
+    };
+    (
+        @emit_item $ctx:tt [$($item:tt)*]
+    ) => {
+        $($item)*
+    };
+    (
+        @item $ctx:tt
+        $(#[$($attrs:tt)+])*
+        pub ($($vis:tt)+) $keyword:ident $($it:tt)+
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs $ctx [pub ($($vis)+) $keyword $($it)+]
+            [] [] [] []
+            [$([$($attrs)+])*]
+        }
+    };
+    (
+        @item $ctx:tt
+        $(#[$($attrs:tt)+])*
+        pub $keyword:ident $($it:tt)+
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs $ctx [pub $keyword $($it)+]
+            [] [] [] []
+            [$([$($attrs)+])*]
+        }
+    };
+    (
+        @item $ctx:tt
+        $(#[$($attrs:tt)+])*
+        $keyword:ident $($it:tt)+
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs $ctx [$keyword $($it)+]
+            [] [] [] []
+            [$([$($attrs)+])*]
+        }
+    };
+    (
+        @item $ctx:tt
+        $(#[$($attrs:tt)+])*
+        $vis:vis $keyword:ident $($it:tt)+
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs $ctx [$vis $keyword $($it)+]
+            [] [] [] []
+            [$([$($attrs)+])*]
+        }
+    };
+    (
+        @item $ctx:tt $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_error $($it)*
+        }
+    };
+    (
+        @item_error # [$($attr:tt)*] $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_error $($it)*
+        }
+    };
+    (
+        @item_error
+    ) => {
+        ::core::compile_error!("expected an item after attributes");
+    };
+    (
+        @item_error $($it:tt)+
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "expected an item, found `", ::core::stringify!($($it)+), "`"
+        ));
+    };
+    (
+        @split_attrs $ctx:tt [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] [$([$($other_attrs:tt)+])*] [$($attr_macro:tt)?]
+        [[derive($($derive_attr:tt)+)] $([$($attrs:tt)+])*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs $ctx [$($it)+]
+            [$($derive_attrs)* $([# $($run)+])? [() $($derive_attr)+]] []
+            [$([$($other_attrs)+])*]
+            [$($attr_macro)?]
+            [$([$($attrs)+])*]
+        }
+    };
+    (
+        @split_attrs $ctx:tt [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] [$([$($other_attrs:tt)+])*] [$($attr_macro:tt)?]
+        [[cfg_attr($pred:meta, derive($($derive_attr:tt)*) $(, $($cfg_attrs:tt)*)?)] $([$($attrs:tt)+])*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs $ctx [$($it)+]
+            [$($derive_attrs)* $([# $($run)+])? [($pred) $($derive_attr)*]] []
+            [$([$($other_attrs)+])*]
+            [$($attr_macro)?]
+            [$([cfg_attr($pred, $($cfg_attrs)*)])? $([$($attrs)+])*]
+        }
+    };
+    (
+        @split_attrs $ctx:tt [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] [$([$($other_attrs:tt)+])*] [$($attr_macro:tt)?]
+        [
+            [cfg_attr($pred:meta, cfg_attr($nested_pred:meta, $($nested_attrs:tt)*) $(, $($cfg_attrs:tt)*)?)]
+            $([$($attrs:tt)+])*
+        ]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs $ctx [$($it)+]
+            [$($derive_attrs)*] [$($($run)+)?]
+            [$([$($other_attrs)+])*]
+            [$($attr_macro)?]
+            [
+                [cfg_attr(all($pred, $nested_pred), $($nested_attrs)*)]
+                $([cfg_attr($pred, $($cfg_attrs)*)])?
+                $([$($attrs)+])*
+            ]
+        }
+    };
+    (
+        @split_attrs $ctx:tt [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] [$([$($other_attrs:tt)+])*] [$($attr_macro:tt)?]
+        [[cfg_attr($pred:meta $(,)?)] $([$($attrs:tt)+])*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs $ctx [$($it)+]
+            [$($derive_attrs)*] [$($($run)+)?]
+            [$([$($other_attrs)+])*]
+            [$($attr_macro)?]
+            [$([$($attrs)+])*]
+        }
+    };
+    (
+        @split_attrs $ctx:tt [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] [$([$($other_attrs:tt)+])*] [$($attr_macro:tt)?]
+        [[cfg_attr($pred:meta, $attr:meta $(, $($cfg_attrs:tt)*)?)] $([$($attrs:tt)+])*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs $ctx [$($it)+]
+            [$($derive_attrs)*] [$($($run)+)? #[cfg_attr($pred, $attr)]]
+            [$([$($other_attrs)+])* [cfg_attr($pred, $attr)]]
+            [$($attr_macro)?]
+            [$([cfg_attr($pred, $($cfg_attrs)*)])? $([$($attrs)+])*]
+        }
+    };
+    (
+        @split_attrs $ctx:tt [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] [$([$($other_attrs:tt)+])*] []
+        [[$($attr_macro_root:ident)? $(:: $attr_macro_segment:ident)* ! $(($($attr_macro_args:tt)*))?] $([$($attrs:tt)+])*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs $ctx [$($it)+]
+            [$($derive_attrs)*] [$($($run)+)?]
+            [$([$($other_attrs)+])*]
+            [[apply [$($attr_macro_root)? $(:: $attr_macro_segment)*] ( $($($attr_macro_args)*)? )]]
+            [$([$($attrs)+])*]
+        }
+    };
+    (
+        @split_attrs $ctx:tt [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] [$([$($other_attrs:tt)+])*] []
+        [[@chain $($attr_macro_root:ident)? $(:: $attr_macro_segment:ident)* ! $(($($attr_macro_args:tt)*))?] $([$($attrs:tt)+])*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs $ctx [$($it)+]
+            [$($derive_attrs)*] [$($($run)+)?]
+            [$([$($other_attrs)+])*]
+            [[chain [$($attr_macro_root)? $(:: $attr_macro_segment)*] ( $($($attr_macro_args)*)? )]]
+            [$([$($attrs)+])*]
+        }
+    };
+    (
+        @split_attrs $ctx:tt [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] [$([$($other_attrs:tt)+])*] [[chain $($attr_macro:tt)+]]
+        [[$(@ $chain:ident)? $($attr_macro_root:ident)? $(:: $attr_macro_segment:ident)* ! $(($($attr_macro_args:tt)*))?] $([$($attrs:tt)+])*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs $ctx [$($it)+]
+            [$($derive_attrs)*]
+            [$($($run)+)? #[$(@ $chain)? $($attr_macro_root)? $(:: $attr_macro_segment)* ! ( $($($attr_macro_args)*)? )]]
+            [$([$($other_attrs)+])*]
+            [[chain $($attr_macro)+]]
+            [$([$($attrs)+])*]
+        }
+    };
+    (
+        @split_attrs $ctx:tt [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] [$([$($other_attrs:tt)+])*] [[apply [$($attr_macro:tt)+] $attr_macro_args:tt]]
+        [[$(@ $chain:ident)? $($other_attr_macro_root:ident)? $(:: $other_attr_macro_segment:ident)* ! $($other_attr_macro_tail:tt)*] $([$($attrs:tt)+])*]
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "attribute macro `", ::core::stringify!($($attr_macro)+), "!` is followed by another attribute macro; ",
+            "mark it with `@chain` (`#[@chain ", ::core::stringify!($($attr_macro)+), "!(...)]`) to pass the item on"
+        ));
+    };
+    (
+        @split_attrs $ctx:tt [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] [$([$($other_attrs:tt)+])*] [$($attr_macro:tt)?]
+        [[$(@ $chain:ident)? $($attr_macro_root:ident)? $(:: $attr_macro_segment:ident)* ! $($attr_macro_tail:tt)+] $([$($attrs:tt)+])*]
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "malformed attribute macro `#[",
+            ::core::stringify!($(@ $chain)? $($attr_macro_root)? $(:: $attr_macro_segment)* ! $($attr_macro_tail)+),
+            "]`, expected `#[Name!]` or `#[Name!(...)]`"
+        ));
+    };
+    (
+        @split_attrs $ctx:tt [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] [$([$($other_attrs:tt)+])*] [$($attr_macro:tt)?]
+        [$([$attr:ident $(= $value:tt)?])+]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs $ctx [$($it)+]
+            [$($derive_attrs)*] [$($($run)+)? $(#[$attr $(= $value)?])+]
+            [$([$($other_attrs)+])* $([$attr $(= $value)?])+]
+            [$($attr_macro)?]
+            []
+        }
+    };
+    (
+        @split_attrs $ctx:tt [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] [$([$($other_attrs:tt)+])*] [$($attr_macro:tt)?]
+        [
+            [$a0:ident $(= $v0:tt)?]
+            [$a1:ident $(= $v1:tt)?]
+            [$a2:ident $(= $v2:tt)?]
+            [$a3:ident $(= $v3:tt)?]
+            [$a4:ident $(= $v4:tt)?]
+            [$a5:ident $(= $v5:tt)?]
+            [$a6:ident $(= $v6:tt)?]
+            [$a7:ident $(= $v7:tt)?]
+            $([$($attrs:tt)+])*
+        ]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs $ctx [$($it)+]
+            [$($derive_attrs)*]
+            [
+                $($($run)+)?
+                #[$a0 $(= $v0)?]
+                #[$a1 $(= $v1)?]
+                #[$a2 $(= $v2)?]
+                #[$a3 $(= $v3)?]
+                #[$a4 $(= $v4)?]
+                #[$a5 $(= $v5)?]
+                #[$a6 $(= $v6)?]
+                #[$a7 $(= $v7)?]
+            ]
+            [
+                $([$($other_attrs)+])*
+                [$a0 $(= $v0)?]
+                [$a1 $(= $v1)?]
+                [$a2 $(= $v2)?]
+                [$a3 $(= $v3)?]
+                [$a4 $(= $v4)?]
+                [$a5 $(= $v5)?]
+                [$a6 $(= $v6)?]
+                [$a7 $(= $v7)?]
+            ]
+            [$($attr_macro)?]
+            [$([$($attrs)+])*]
+        }
+    };
+    (
+        @split_attrs $ctx:tt [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] [$([$($other_attrs:tt)+])*] [$($attr_macro:tt)?]
+        [[$($attr:tt)+] $([$($attrs:tt)+])*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs $ctx [$($it)+]
+            [$($derive_attrs)*] [$($($run)+)? #[$($attr)+]]
+            [$([$($other_attrs)+])* [$($attr)+]]
+            [$($attr_macro)?]
+            [$([$($attrs)+])*]
+        }
+    };
+    (
+        @split_attrs (remote) [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] [$([$($other_attrs:tt)+])*] [$($attr_macro:tt)?]
+        []
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs (remote) [$($it)+] [$([$($other_attrs)+])*] [[] [] [[] []] []] []
+            [$($derive_attrs)* $([# $($run)+])?]
+        }
+    };
+    (
+        @split_attrs $ctx:tt [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] $other_attrs:tt [[chain $($attr_macro:tt)+]]
+        []
+    ) => {
+        $crate::macro_attr_impl! {
+            @chain $ctx [$($it)+] [$($attr_macro)+] [] $($derive_attrs)* $([# $($run)+])?
+        }
+    };
+    (
+        @split_attrs $ctx:tt [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] [$([$($other_attrs:tt)+])*] [[apply $($attr_macro:tt)+]]
+        []
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs (apply [$($attr_macro)+] $ctx) [$($it)+] [$([$($other_attrs)+])*] [[] [] [[] []] []] []
+            [$($derive_attrs)* $([# $($run)+])?]
+        }
+    };
+    (
+        @split_attrs $ctx:tt [$($it:tt)+]
+        [$($derive_attrs:tt)*] [$($($run:tt)+)?] [$([$($other_attrs:tt)+])*] []
+        []
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs $ctx [$($it)+] [$([$($other_attrs)+])*] [[] [] [[] []] []] []
+            [$($derive_attrs)* $([# $($run)+])?]
+        }
+    };
+    (
+        @chain $ctx:tt $it:tt $attr_macro:tt [$($attrs:tt)*] [() $($derive:tt)*] $($derive_attrs:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @chain $ctx $it $attr_macro [$($attrs)* #[derive($($derive)*)]] $($derive_attrs)*
+        }
+    };
+    (
+        @chain $ctx:tt $it:tt $attr_macro:tt [$($attrs:tt)*] [($($pred:tt)+) $($derive:tt)*] $($derive_attrs:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @chain $ctx $it $attr_macro [$($attrs)* #[cfg_attr($($pred)+, derive($($derive)*))]] $($derive_attrs)*
+        }
+    };
+    (
+        @chain $ctx:tt $it:tt $attr_macro:tt [$($attrs:tt)*] [# $($run:tt)*] $($derive_attrs:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @chain $ctx $it $attr_macro [$($attrs)* $($run)*] $($derive_attrs)*
+        }
+    };
+    (
+        @chain $ctx:tt [$($it:tt)+]
+        [[$($attr_macro:tt)+] ( $($attr_macro_args:tt)* )]
+        [$($attrs:tt)*]
+    ) => {
+        $($attr_macro)+! {
+            @chain $crate::macro_attr_impl { @items $ctx }
+            ( $($attr_macro_args)* )
+            $($attrs)*
+            $($it)+
+        }
+    };
+    (
+        @split_derive_attrs $ctx:tt $it:tt $other_attrs:tt $sorted:tt [$($derives:tt)*]
+        [[# $($run:tt)*] $($derive_attrs:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs $ctx $it $other_attrs $sorted
+            [$($derives)* [# [$($run)*]]]
+            [$($derive_attrs)*]
+        }
+    };
+    (
+        @split_derive_attrs $ctx:tt $it:tt $other_attrs:tt $sorted:tt [$($derives:tt)*]
+        [
+            [
+                ()
+                $(
+                    $(@ $derive_option:ident $(($($derive_option_args:tt)*))?)*
+                    $(:: $(@ $absolute:tt)?)? $derive_root:ident $(:: $derive_segment:ident)* $(($($std_derive_args:tt)*))?
+                    $(! $(($($macro_derive_args:tt)*))? $([$($macro_derive_list:tt)*])? $({$($macro_derive_block:tt)*})?)?
+                    $(if $derive_cond:meta)?
+                ),* $(,)?
+            ]
+            $($derive_attrs:tt)*
+        ]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs $ctx $it $other_attrs $sorted
+            [
+                $($derives)*
+                $([
+                    $(if [$derive_cond])?
+                    $(! [$(($($macro_derive_args)*))? $(($($macro_derive_list)*))? $(($($macro_derive_block)*))?])?
+                    [$($derive_option $(($($derive_option_args)*))?)*]
+                    [$(:: $(@ $absolute)?)? $derive_root $(:: $derive_segment)*] [$(($($std_derive_args)*))?]
+                    []
+                    [[$(:: $(@ $absolute)?)? $derive_root $(:: $derive_segment)* $(($($std_derive_args)*))?]]
+                ])*
+                [~]
+            ]
+            [$($derive_attrs)*]
+        }
+    };
+    (
+        @split_derive_attrs $ctx:tt $it:tt $other_attrs:tt $sorted:tt [$($derives:tt)*]
+        [
+            [
+                ($cond:meta)
+                $(
+                    $(@ $derive_option:ident $(($($derive_option_args:tt)*))?)*
+                    $(:: $(@ $absolute:tt)?)? $derive_root:ident $(:: $derive_segment:ident)* $(($($std_derive_args:tt)*))?
+                    $(! $(($($macro_derive_args:tt)*))? $([$($macro_derive_list:tt)*])? $({$($macro_derive_block:tt)*})?)?
+                    $(if $derive_cond:meta)?
+                ),* $(,)?
+            ]
+            $($derive_attrs:tt)*
+        ]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs $ctx $it $other_attrs $sorted
+            [
+                $($derives)*
+                $([
+                    $(if [$derive_cond])?
+                    $(! [$(($($macro_derive_args)*))? $(($($macro_derive_list)*))? $(($($macro_derive_block)*))?])?
+                    [$($derive_option $(($($derive_option_args)*))?)*]
+                    [$(:: $(@ $absolute)?)? $derive_root $(:: $derive_segment)*] [$(($($std_derive_args)*))?]
+                    [#[cfg($cond)]]
+                    [[#[cfg($cond)] $(:: $(@ $absolute)?)? $derive_root $(:: $derive_segment)* $(($($std_derive_args)*))?]]
+                ])*
+                [~]
+            ]
+            [$($derive_attrs)*]
+        }
+    };
+    (
+        @split_derive_attrs $ctx:tt $it:tt $other_attrs:tt
+        [$item_attrs:tt $macro_derives:tt $siblings:tt [$($pending_derives:tt)*]] [$($derives:tt)*]
+        []
+    ) => {
+        $crate::macro_attr_impl! {
+            @sort_derives $ctx $it $other_attrs $item_attrs $macro_derives $siblings
+            $($derives)* $($pending_derives)*
+        }
+    };
+    (
+        @split_derive_attrs $ctx:tt $it:tt $other_attrs:tt $sorted:tt [$($derives:tt)*]
+        [[($($cond:tt)*) $($derive_attr:tt)*] $($derive_attrs:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @find_malformed_derive $($derive_attr)*
+        }
+    };
+    (
+        @find_malformed_derive
+    ) => {
+    };
+    (
+        @find_malformed_derive
+        $(@ $derive_option:ident $(($($derive_option_args:tt)*))?)*
+        $(:: $(@ $absolute:tt)?)? $derive_root:ident $(:: $derive_segment:ident)* $(($($std_derive_args:tt)*))?
+        $(! $(($($macro_derive_args:tt)*))? $([$($macro_derive_list:tt)*])? $({$($macro_derive_block:tt)*})?)?
+        $(if $derive_cond:meta)?
+        $(, $($derives:tt)*)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @find_malformed_derive $($($derives)*)?
+        }
+    };
+    (
+        @find_malformed_derive $($derives:tt)+
+    ) => {
+        $crate::macro_attr_impl! {
+            @malformed_derive [] $($derives)+
+        }
+    };
+    (
+        @malformed_derive [$($derive:tt)*] $(, $($tail:tt)*)?
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "malformed derive `", ::core::stringify!($($derive)*), "`, ",
+            "expected `Name`, `Name!` or `Name!(...)`, optionally preceded by `@option`s"
+        ));
+    };
+    (
+        @malformed_derive [$($derive:tt)*] $token:tt $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @malformed_derive [$($derive)* $token] $($tail)*
+        }
+    };
+    (
+        @sort_derives $ctx:tt $it:tt $other_attrs:tt $item_attrs:tt $macro_derives:tt $siblings:tt
+        $([[] $($std_derive:tt)*])*
+        [
+            if [$cond:meta] ! $macro_derive_args:tt [$($derive_options:tt)*] [$($derive:tt)+] [$($derive_args:tt)*]
+            [$(#[cfg($group_cond:meta)])?] $sibling:tt
+        ]
+        $($derives:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @sort_derives $ctx $it $other_attrs $item_attrs $macro_derives $siblings
+            $([[] $($std_derive)*])*
+            [
+                ! $macro_derive_args [$($derive_options)*] [$($derive)+] [$($derive_args)*]
+                [#[cfg(all($($group_cond,)? $cond))]] [[#[cfg(all($($group_cond,)? $cond))] $($derive)+ $($derive_args)*]]
+            ]
+            $($derives)*
+        }
+    };
+    (
+        @sort_derives $ctx:tt $it:tt $other_attrs:tt $item_attrs:tt $macro_derives:tt $siblings:tt
+        $([[] $($std_derive:tt)*])*
+        [if [$cond:meta] [] [$($derive:tt)+] [$($derive_args:tt)*] [$(#[cfg($group_cond:meta)])?] $sibling:tt]
+        $($derives:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @sort_derives $ctx $it $other_attrs $item_attrs $macro_derives $siblings
+            $([[] $($std_derive)*])*
+            [~]
+            [
+                # [#[cfg_attr(all($($group_cond,)? $cond), derive($($derive)+ $($derive_args)*))]]
+                [#[cfg(all($($group_cond,)? $cond))] $($derive)+ $($derive_args)*]
+            ]
+            $($derives)*
+        }
+    };
+    (
+        @sort_derives $ctx:tt $it:tt $other_attrs:tt $item_attrs:tt $macro_derives:tt $siblings:tt
+        $([[] $($std_derive:tt)*])*
+        [if [$cond:meta] [$($derive_options:tt)+] $($derive:tt)*]
+        $($derives:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @sort_derives $ctx $it $other_attrs $item_attrs $macro_derives $siblings
+            [[$($derive_options)+] $($derive)*]
+        }
+    };
+    (
+        @sort_derives $ctx:tt $it:tt $other_attrs:tt [$($item_attrs:tt)*] $macro_derives:tt
+        [[$($std_siblings:tt)*] $macro_siblings:tt]
+        [# [$($run:tt)*] $([$($std_sibling:tt)+])?]
+        $($derives:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @sort_derives $ctx $it $other_attrs [$($item_attrs)* $($run)*] $macro_derives
+            [[$($std_siblings)* $([$($std_sibling)+])?] $macro_siblings]
+            $($derives)*
+        }
+    };
+    (
+        @sort_derives $ctx:tt $it:tt $other_attrs:tt $item_attrs:tt $macro_derives:tt $siblings:tt
+        [~]
+        $($derives:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @sort_derives $ctx $it $other_attrs $item_attrs $macro_derives $siblings
+            $($derives)*
+        }
+    };
+    (
+        @sort_derives $ctx:tt $it:tt $other_attrs:tt
+        [$($item_attrs:tt)*] $macro_derives:tt [[$($std_siblings:tt)*] $macro_siblings:tt]
+        $([[] [$($std_derive:tt)+] [$($std_derive_args:tt)*] [] [$($std_sibling:tt)*]])+
+        [~]
+        $($derives:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @sort_derives $ctx $it $other_attrs
+            [$($item_attrs)* #[derive($($($std_derive)+ $($std_derive_args)*),+)]] $macro_derives
+            [[$($std_siblings)* $($($std_sibling)*)+] $macro_siblings]
+            $($derives)*
+        }
+    };
+    (
+        @sort_derives $ctx:tt $it:tt $other_attrs:tt
+        [$($item_attrs:tt)*] $macro_derives:tt [[$($std_siblings:tt)*] $macro_siblings:tt]
+        [[] [$($first_std_derive:tt)+] [$($first_std_derive_args:tt)*] [#[cfg($cond:meta)]] [$($first_std_sibling:tt)*]]
+        $([[] [$($std_derive:tt)+] [$($std_derive_args:tt)*] [$($std_cfg:tt)*] [$($std_sibling:tt)*]])*
+        [~]
+        $($derives:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @sort_derives $ctx $it $other_attrs
+            [
+                $($item_attrs)*
+                #[cfg_attr($cond, derive(
+                    $($first_std_derive)+ $($first_std_derive_args)* $(, $($std_derive)+ $($std_derive_args)*)*
+                ))]
+            ]
+            $macro_derives
+            [[$($std_siblings)* $($first_std_sibling)* $($($std_sibling)*)*] $macro_siblings]
+            $($derives)*
+        }
+    };
+    (
+        @sort_derives $ctx:tt $it:tt $other_attrs:tt $item_attrs:tt $macro_derives:tt $siblings:tt
+        $([[] $($std_derive:tt)*])*
+        [! [] [bundle] [$($bundle:tt)+] [] [$(#[cfg($cond:meta)])?] $($bundle_tail:tt)*]
+        $($derives:tt)*
+    ) => {
+        $($bundle)+! {
+            @macro_attr_bundle [$crate::macro_attr_impl] [
+                @bundle $ctx $it $other_attrs
+                [$item_attrs $macro_derives $siblings [$($derives)*]]
+                ($($cond)?)
+                [$([[] $($std_derive)*])* [~]]
+            ]
+        }
+    };
+    (
+        @sort_derives $ctx:tt $it:tt $other_attrs:tt $item_attrs:tt $macro_derives:tt $siblings:tt
+        $([[] $($std_derive:tt)*])*
+        [! [$($bundle_args:tt)+] [bundle] [$($bundle:tt)+] $($bundle_tail:tt)*]
+        $($derives:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "derive bundle `", ::core::stringify!($($bundle)+), "!` takes no arguments"
+        ));
+    };
+    (
+        @bundle $ctx:tt $it:tt $other_attrs:tt $sorted:tt $cond:tt [$($pending_derives:tt)*]
+        [$($derives:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs $ctx $it $other_attrs $sorted [$($pending_derives)*] [[$cond $($derives)*]]
+        }
+    };
+    (
+        @sort_derives $ctx:tt $it:tt $other_attrs:tt
+        $item_attrs:tt [$($macro_derives:tt)*] [$std_siblings:tt [$($macro_siblings:tt)*]]
+        $([[] $($std_derive:tt)*])*
+        [
+            ! [$(($($macro_derive_args:tt)*))?] [$($macro_derive_options:tt)*] [$($macro_derive:tt)+] []
+            [$($cfg:tt)*] [$([$($macro_sibling:tt)+])?]
+        ]
+        $($derives:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @sort_derives $ctx $it $other_attrs $item_attrs
+            [$($macro_derives)* [[$($macro_derive)+] ( $($($macro_derive_args)*)? ) [$($macro_derive_options)*] [$($cfg)*]]]
+            [$std_siblings [$($macro_siblings)* $([$($macro_sibling)+ !])?]]
+            $([[] $($std_derive)*])*
+            $($derives)*
+        }
+    };
+    (
+        @sort_derives (remote) $it:tt $other_attrs:tt $item_attrs:tt [] $siblings:tt
+    ) => {
+    };
+    (
+        @sort_derives (remote) $it:tt $other_attrs:tt $item_attrs:tt [$($macro_derives:tt)+] $siblings:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @expand () $it $other_attrs $siblings [$($macro_derives)+]
+        }
+    };
+    (
+        @sort_derives (apply [[$($attr_macro:tt)+] ( $($attr_macro_args:tt)* )] $ctx:tt) [$($it:tt)+] $other_attrs:tt
+        [$($item_attrs:tt)*] [] $siblings:tt
+    ) => {
+        $($attr_macro)+! {
+            ( $($attr_macro_args)* )
+            $($item_attrs)*
+            $($it)+
+        }
+    };
+    (
+        @sort_derives (apply [[$($attr_macro:tt)+] ( $($attr_macro_args:tt)* )] $ctx:tt) [$($it:tt)+] $other_attrs:tt
+        [$($item_attrs:tt)*] [$($macro_derives:tt)+] $siblings:tt
+    ) => {
+        $($attr_macro)+! {
+            ( $($attr_macro_args)* )
+            $($item_attrs)*
+            $($it)+
+        }
+        $crate::macro_attr_impl! {
+            @expand $ctx [$($it)+] $other_attrs $siblings [$($macro_derives)+]
+        }
+    };
+    (
+        @sort_derives $ctx:tt [$($it:tt)+] $other_attrs:tt [$($item_attrs:tt)*] [] $siblings:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @as_item
+            $($item_attrs)*
+            $($it)+
+        }
+    };
+    (
+        @sort_derives $ctx:tt [$($it:tt)+] $other_attrs:tt $item_attrs:tt [$($macro_derives:tt)+] $siblings:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_item $item_attrs $($it)+
+        }
+        $crate::macro_attr_impl! {
+            @expand $ctx [$($it)+] $other_attrs $siblings [$($macro_derives)+]
+        }
+    };
+    (
+        @sort_derives $ctx:tt $it:tt $other_attrs:tt $item_attrs:tt $macro_derives:tt $siblings:tt
+        $([[] $($std_derive:tt)*])*
+        [
+            $(! [$($macro_derive_args:tt)*])?
+            [$($derive_option:ident $(($($derive_option_args:tt)*))?)*] [$($derive:tt)*] [$($derive_args:tt)*]
+            $($ignored:tt)*
+        ]
+        $($derives:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "malformed derive `",
+            ::core::stringify!(
+                $(@ $derive_option $(($($derive_option_args)*))?)* $($derive)* $($derive_args)* $(! $($macro_derive_args)*)?
+            ),
+            "`, expected `Name`, `Name!` or `Name!(...)`, optionally preceded by `@option`s"
+        ));
+    };
+    (
+        @expand $ctx:tt $it:tt $other_attrs:tt $siblings:tt
+        [$([$macro_derive:tt $macro_derive_args:tt $macro_derive_options:tt $cfg:tt])+]
+    ) => {
+        $(
+            $crate::macro_attr_impl! {
+                @invoke $ctx $cfg $macro_derive $macro_derive_options []
+                [$macro_derive_args $it]
+                $other_attrs $siblings
+            }
+        )+
+    };
+    (
+        @invoke () [$($cfg:tt)*] [$($macro_derive:tt)+] [recurse ($limit:tt) $($macro_derive_options:tt)*] [$($sections:tt)*]
+        [$($input:tt)+]
+        [$([$($other_attrs:tt)+])*] [$($siblings:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @recursion_limit $limit [$($cfg)*] [$($macro_derive)+] [recurse $($macro_derive_options)*] [$($sections)*]
+            [$($input)+]
+            [$([$($other_attrs)+])*] [$($siblings)*]
+        }
+    };
+    (
+        @invoke () [$($cfg:tt)*] [$($macro_derive:tt)+] [recurse $($macro_derive_options:tt)*] [$($sections:tt)*]
+        [$($input:tt)+]
+        [$([$($other_attrs:tt)+])*] [$($siblings:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @invoke ([_ _ _ _]) [$($cfg)*] [$($macro_derive)+] [recurse $($macro_derive_options)*] [$($sections)*]
+            [$($input)+]
+            [$([$($other_attrs)+])*] [$($siblings)*]
+        }
+    };
+    (
+        @invoke ([_ $($budget:tt)*]) [$($cfg:tt)*] [$($macro_derive:tt)+]
+        [recurse $(($($limit:tt)*))? $($macro_derive_options:tt)*] [$($sections:tt)*]
+        [$($input:tt)+]
+        [$([$($other_attrs:tt)+])*] [$($siblings:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @invoke ([_ $($budget)*]) [$($cfg)*] [$($macro_derive)+] [$($macro_derive_options)*]
+            [$($sections)* @recurse $crate::macro_attr_impl { @items ([$($budget)*]) }]
+            [$($input)+]
+            [$([$($other_attrs)+])*] [$($siblings)*]
+        }
+    };
+    (
+        @invoke ([]) [$($cfg:tt)*] [$($macro_derive:tt)+] [recurse $($macro_derive_options:tt)*] [$($sections:tt)*]
+        [$($input:tt)+]
+        [$([$($other_attrs:tt)+])*] [$($siblings:tt)*]
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "recursion limit reached while invoking macro derive `", ::core::stringify!($($macro_derive)+), "!`; ",
+            "consider raising it with `@recurse(N)` on the outermost recursive derivation"
+        ));
+    };
+    (
+        @invoke $ctx:tt [$($cfg:tt)*] [$($macro_derive:tt)+]
+        [$macro_derive_option:ident ($($macro_derive_option_args:tt)*) $($macro_derive_options:tt)*] [$($sections:tt)*]
+        [$($input:tt)+]
+        [$([$($other_attrs:tt)+])*] [$($siblings:tt)*]
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "option `@", ::core::stringify!($macro_derive_option), "` ",
+            "for macro derive `", ::core::stringify!($($macro_derive)+), "!` takes no arguments"
+        ));
+    };
+    (
+        @invoke $ctx:tt [$($cfg:tt)*] [$($macro_derive:tt)+] [bundle $($macro_derive_options:tt)*] [$($sections:tt)*]
+        [$($input:tt)+]
+        [$([$($other_attrs:tt)+])*] [$($siblings:tt)*]
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "option `@bundle` for derive bundle `", ::core::stringify!($($macro_derive)+), "!` ",
+            "cannot be combined with other options"
+        ));
+    };
+    (
+        @invoke $ctx:tt [$($cfg:tt)*] [$($macro_derive:tt)+] [attrs $($macro_derive_options:tt)*] [$($sections:tt)*]
+        [$($input:tt)+]
+        [$([$($other_attrs:tt)+])*] [$($siblings:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @invoke $ctx [$($cfg)*] [$($macro_derive)+] [$($macro_derive_options)*]
+            [$($sections)* @attrs [$(#[$($other_attrs)+])*]]
+            [$($input)+]
+            [$([$($other_attrs)+])*] [$($siblings)*]
+        }
+    };
+    (
+        @invoke $ctx:tt [$($cfg:tt)*] [$($macro_derive:tt)+] [derives $($macro_derive_options:tt)*] [$($sections:tt)*]
+        [$($input:tt)+]
+        [$([$($other_attrs:tt)+])*] [[$([$($std_sibling:tt)+])*] [$([$($macro_sibling:tt)+])*]]
+    ) => {
+        $crate::macro_attr_impl! {
+            @invoke $ctx [$($cfg)*] [$($macro_derive)+] [$($macro_derive_options)*]
+            [$($sections)* @derives [$($($std_sibling)+),*] [$($($macro_sibling)+),*]]
+            [$($input)+]
+            [$([$($other_attrs)+])*] [[$([$($std_sibling)+])*] [$([$($macro_sibling)+])*]]
+        }
+    };
+    (
+        @invoke $ctx:tt [$($cfg:tt)*] [$($macro_derive:tt)+] [kind $($macro_derive_options:tt)*] [$($sections:tt)*]
+        [( $($macro_derive_args:tt)* ) [$($it:tt)+]]
+        $other_attrs:tt $siblings:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_kind
+            [
+                @invoke_kind $ctx [$($cfg)*] [$($macro_derive)+] [$($macro_derive_options)*] [$($sections)*]
+                [( $($macro_derive_args)* ) [$($it)+]]
+                $other_attrs $siblings
+            ]
+            $($it)+
+        }
+    };
+    (
+        @invoke_kind $ctx:tt $cfg:tt $macro_derive:tt $macro_derive_options:tt [$($sections:tt)*]
+        $input:tt $other_attrs:tt $siblings:tt $kind:ident
+    ) => {
+        $crate::macro_attr_impl! {
+            @invoke $ctx $cfg $macro_derive $macro_derive_options [$($sections)* @kind [$kind]]
+            $input $other_attrs $siblings
+        }
+    };
+    (
+        @invoke $ctx:tt [$($cfg:tt)*] [$($macro_derive:tt)+] [v2 $($macro_derive_options:tt)*] [$($sections:tt)*]
+        [( $($macro_derive_args:tt)* ) [$($it:tt)+]]
+        $other_attrs:tt $siblings:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_kind
+            [
+                @invoke_v2 $ctx [$($cfg)*] [$($macro_derive)+] [$($macro_derive_options)*] [$($sections)*]
+                [( $($macro_derive_args)* ) [$($it)+]]
+                $other_attrs $siblings
+            ]
+            $($it)+
+        }
+    };
+    (
+        @invoke_v2 $ctx:tt $cfg:tt $macro_derive:tt $macro_derive_options:tt [$($sections:tt)*]
+        $input:tt $other_attrs:tt $siblings:tt $kind:ident
+    ) => {
+        $crate::macro_attr_impl! {
+            @invoke $ctx $cfg $macro_derive $macro_derive_options [@v2 [$kind] $($sections)*]
+            $input $other_attrs $siblings
+        }
+    };
+    (
+        @invoke $ctx:tt [$($cfg:tt)*] [$($macro_derive:tt)+] [parsed parsed $($macro_derive_options:tt)*] [$($sections:tt)*]
+        [$($input:tt)+]
+        [$([$($other_attrs:tt)+])*] [$($siblings:tt)*]
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "option `@parsed` for macro derive `", ::core::stringify!($($macro_derive)+), "!` is given twice"
+        ));
+    };
+    (
+        @invoke $ctx:tt $cfg:tt $macro_derive:tt
+        [parsed $macro_derive_option:ident $($macro_derive_options:tt)*] $sections:tt
+        $input:tt $other_attrs:tt $siblings:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @invoke $ctx $cfg $macro_derive [$macro_derive_option $($macro_derive_options)* parsed] $sections
+            $input $other_attrs $siblings
+        }
+    };
+    (
+        @invoke $ctx:tt [$($cfg:tt)*] [$($macro_derive:tt)+] [parsed $($macro_derive_options:tt)*] [$($sections:tt)*]
+        [( $($macro_derive_args:tt)* ) [$($it:tt)+]]
+        [$([$($other_attrs:tt)+])*] [$($siblings:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_item
+            [
+                $ctx [$($cfg)*] [$($macro_derive)+] [$($macro_derive_options)*] [$($sections)*]
+                [( $($macro_derive_args)* ) [$($it)+]]
+                [$([$($other_attrs)+])*] [$($siblings)*]
+            ]
+            $($it)+
+        }
+    };
+    (
+        @invoke $ctx:tt [$($cfg:tt)*] [$($macro_derive:tt)+] [$macro_derive_option:ident $($macro_derive_options:tt)*] [$($sections:tt)*]
+        [$($input:tt)+]
+        [$([$($other_attrs:tt)+])*] [$($siblings:tt)*]
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown option `@", ::core::stringify!($macro_derive_option), "` ",
+            "for macro derive `", ::core::stringify!($($macro_derive)+), "!`"
+        ));
+    };
+    (
+        @invoke $ctx:tt [$($cfg:tt)*] [$($macro_derive:tt)+] []
+        [@v2 [$kind:ident] $(@recurse $recurse:path { $($recurse_state:tt)* })? $(@parsed { $($parsed:tt)* })?]
+        [( $($macro_derive_args:tt)* ) [$($it:tt)+]]
+        [$([$($other_attrs:tt)+])*] [[$([$($std_sibling:tt)+])*] [$([$($macro_sibling:tt)+])*]]
+    ) => {
+        $($cfg)*
+        $($macro_derive)+! {
+            @macro_attr v2 {
+                kind: $kind,
+                args: ( $($macro_derive_args)* ),
+                attrs: [$(#[$($other_attrs)+])*],
+                derives: [$($($std_sibling)+),*],
+                macro_derives: [$($($macro_sibling)+),*],
+                recurse: [$($recurse { $($recurse_state)* })?],
+                parsed: [$({ $($parsed)* })?],
+            }
+            $($it)+
+        }
+    };
+    (
+        @invoke $ctx:tt [$($cfg:tt)*] [$($macro_derive:tt)+] [] [@v2 $($sections:tt)*]
+        [$($input:tt)+]
+        [$([$($other_attrs:tt)+])*] [$($siblings:tt)*]
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "option `@v2` for macro derive `", ::core::stringify!($($macro_derive)+), "!` ",
+            "cannot be combined with `@attrs`, `@derives`, `@kind` or another `@v2`"
+        ));
+    };
+    (
+        @invoke $ctx:tt [$($cfg:tt)*] [$($macro_derive:tt)+] [] []
+        [( $($macro_derive_args:tt)* ) [$($it:tt)+]]
+        [$([$($other_attrs:tt)+])*] [$($siblings:tt)*]
+    ) => {
+        $($cfg)*
+        macro_rules! __macro_attr_v2 {
+            () => {
+                $crate::macro_attr_impl! {
+                    @invoke $ctx [$($cfg)*] [$($macro_derive)+] [v2] []
+                    [( $($macro_derive_args)* ) [$($it)+]]
+                    [$([$($other_attrs)+])*] [$($siblings)*]
+                }
+            };
+        }
+        $($cfg)*
+        $($macro_derive)+! {
+            ( $($macro_derive_args)* )
+            $($it)+
+        }
+    };
+    (
+        @invoke $ctx:tt [$($cfg:tt)*] [$($macro_derive:tt)+] [] [$($sections:tt)*]
+        [( $($macro_derive_args:tt)* ) [$($it:tt)+]]
+        [$([$($other_attrs:tt)+])*] [$($siblings:tt)*]
+    ) => {
+        $($cfg)*
+        macro_rules! __macro_attr_v2 {
+            () => {
+                ::core::compile_error!(::core::concat!(
+                    "macro derive `", ::core::stringify!($($macro_derive)+), "!` uses the second version ",
+                    "of the invocation protocol, use `@v2` instead of the other options"
+                ));
+            };
+        }
+        $($cfg)*
+        $($macro_derive)+! {
+            $($sections)*
+            ( $($macro_derive_args)* )
+            $($it)+
+        }
+    };
+    (
+        @item_kind [$($continuation:tt)+] $vis:vis $keyword:ident $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_keyword [$($continuation)+] $keyword $($it)*
+        }
+    };
+    (
+        @item_keyword [$($continuation:tt)+] const fn $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ fn }
+    };
+    (
+        @item_keyword [$($continuation:tt)+] const $qualifier:ident $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_const_keyword [$($continuation)+] $qualifier $($it)*
+        }
+    };
+    (
+        @item_keyword [$($continuation:tt)+] extern crate $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ extern_crate }
+    };
+    (
+        @item_keyword [$($continuation:tt)+] extern $($abi:literal)? fn $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ fn }
+    };
+    (
+        @item_keyword [$($continuation:tt)+] $qualifier:ident $keyword:ident $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_qualified_keyword [$($continuation)+] $qualifier $keyword $($it)*
+        }
+    };
+    (
+        @item_keyword [$($continuation:tt)+] $keyword:ident $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_known_keyword [$($continuation)+] $keyword
+        }
+    };
+    (
+        @item_const_keyword [$($continuation:tt)+] unsafe $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_keyword [$($continuation)+] unsafe $($it)*
+        }
+    };
+    (
+        @item_const_keyword [$($continuation:tt)+] async $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_keyword [$($continuation)+] async $($it)*
+        }
+    };
+    (
+        @item_const_keyword [$($continuation:tt)+] extern $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_keyword [$($continuation)+] extern $($it)*
+        }
+    };
+    (
+        @item_const_keyword [$($continuation:tt)+] $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ const }
+    };
+    (
+        @item_qualified_keyword [$($continuation:tt)+] unsafe $keyword:ident $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_keyword [$($continuation)+] $keyword $($it)*
+        }
+    };
+    (
+        @item_qualified_keyword [$($continuation:tt)+] async $keyword:ident $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_keyword [$($continuation)+] $keyword $($it)*
+        }
+    };
+    (
+        @item_qualified_keyword [$($continuation:tt)+] auto $keyword:ident $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_keyword [$($continuation)+] $keyword $($it)*
+        }
+    };
+    (
+        @item_qualified_keyword [$($continuation:tt)+] $keyword:ident $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_known_keyword [$($continuation)+] $keyword
+        }
+    };
+    (
+        @item_known_keyword [$($continuation:tt)+] struct
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ struct }
+    };
+    (
+        @item_known_keyword [$($continuation:tt)+] enum
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ enum }
+    };
+    (
+        @item_known_keyword [$($continuation:tt)+] union
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ union }
+    };
+    (
+        @item_known_keyword [$($continuation:tt)+] trait
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ trait }
+    };
+    (
+        @item_known_keyword [$($continuation:tt)+] fn
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ fn }
+    };
+    (
+        @item_known_keyword [$($continuation:tt)+] impl
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ impl }
+    };
+    (
+        @item_known_keyword [$($continuation:tt)+] mod
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ mod }
+    };
+    (
+        @item_known_keyword [$($continuation:tt)+] type
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ type }
+    };
+    (
+        @item_known_keyword [$($continuation:tt)+] const
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ const }
+    };
+    (
+        @item_known_keyword [$($continuation:tt)+] static
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ static }
+    };
+    (
+        @item_known_keyword [$($continuation:tt)+] use
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ use }
+    };
+    (
+        @item_known_keyword [$($continuation:tt)+] extern
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ extern }
+    };
+    (
+        @item_known_keyword [$($continuation:tt)+] macro_rules
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ macro_rules }
+    };
+    (
+        @item_known_keyword [$($continuation:tt)+] $keyword:ident
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ item }
+    };
+    (
+        @item_kind [$($continuation:tt)+] $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! { $($continuation)+ item }
+    };
+    (
+        @parse_item [$($invoke:tt)+] $vis:vis struct $name:ident $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_generics [$($invoke)+] [struct [$vis] $name] $($tail)*
+        }
+    };
+    (
+        @parse_item [$($invoke:tt)+] $vis:vis enum $name:ident $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_generics [$($invoke)+] [enum [$vis] $name] $($tail)*
+        }
+    };
+    (
+        @parse_item [$($invoke:tt)+] $vis:vis union $name:ident $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_generics [$($invoke)+] [union [$vis] $name] $($tail)*
+        }
+    };
+    (
+        @parse_item [$($invoke:tt)+] $vis:vis unsafe trait $name:ident $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_generics [$($invoke)+] [trait [$vis] [unsafe] $name] $($tail)*
+        }
+    };
+    (
+        @parse_item [$($invoke:tt)+] $vis:vis trait $name:ident $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_generics [$($invoke)+] [trait [$vis] [] $name] $($tail)*
+        }
+    };
+    (
+        @parse_item [$ctx:tt [$($cfg:tt)*] [$($macro_derive:tt)+] $($invoke:tt)*] $($it:tt)+
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "option `@parsed` for macro derive `", ::core::stringify!($($macro_derive)+), "!` ",
+            "supports only `struct`, `enum`, `union` and `trait` items"
+        ));
+    };
+    (
+        @parse_generics [$($invoke:tt)+] [$($header:tt)+] < $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_generic_params [$($invoke)+] [$($header)+] [] [] $($tail)*
+        }
+    };
+    (
+        @parse_generics [$($invoke:tt)+] [$($header:tt)+] $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_body [$($invoke)+] [$($header)+] [] $($tail)*
+        }
+    };
+    (
+        @parse_generic_params [$($invoke:tt)+] [$($header:tt)+] [$($params:tt)*] [] > $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_body [$($invoke)+] [$($header)+] [$($params)*] $($tail)*
+        }
+    };
+    (
+        @parse_generic_params [$($invoke:tt)+] [$($header:tt)+] [$($params:tt)*] [<] >> $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_body [$($invoke)+] [$($header)+] [$($params)* >] $($tail)*
+        }
+    };
+    (
+        @parse_generic_params [$($invoke:tt)+] [$($header:tt)+] [$($params:tt)*] [< < $($depth:tt)*] >> $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_generic_params [$($invoke)+] [$($header)+] [$($params)* >>] [$($depth)*] $($tail)*
+        }
+    };
+    (
+        @parse_generic_params [$($invoke:tt)+] [$($header:tt)+] [$($params:tt)*] [< $($depth:tt)*] >= $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_generic_params [$($invoke)+] [$($header)+] [$($params)* >] [$($depth)*] = $($tail)*
+        }
+    };
+    (
+        @parse_generic_params [$($invoke:tt)+] [$($header:tt)+] [$($params:tt)*] [< < $($depth:tt)*] >>= $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_generic_params [$($invoke)+] [$($header)+] [$($params)* >>] [$($depth)*] = $($tail)*
+        }
+    };
+    (
+        @parse_generic_params [$($invoke:tt)+] [$($header:tt)+] [$($params:tt)*] [< $($depth:tt)*] > $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_generic_params [$($invoke)+] [$($header)+] [$($params)* >] [$($depth)*] $($tail)*
+        }
+    };
+    (
+        @parse_generic_params [$($invoke:tt)+] [$($header:tt)+] [$($params:tt)*] [$($depth:tt)*] << $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_generic_params [$($invoke)+] [$($header)+] [$($params)* <<] [< < $($depth)*] $($tail)*
+        }
+    };
+    (
+        @parse_generic_params [$($invoke:tt)+] [$($header:tt)+] [$($params:tt)*] [$($depth:tt)*] < $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_generic_params [$($invoke)+] [$($header)+] [$($params)* <] [< $($depth)*] $($tail)*
+        }
+    };
+    (
+        @parse_generic_params [$($invoke:tt)+] [$($header:tt)+] [$($params:tt)*] [$($depth:tt)*] $token:tt $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_generic_params [$($invoke)+] [$($header)+] [$($params)* $token] [$($depth)*] $($tail)*
+        }
+    };
+    (
+        @parse_body [@trait_type $invoke:tt $trait:tt $items:tt] [trait_type $attrs:tt $name:ident] [$($params:tt)*] $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_type $invoke $trait $items $attrs $name [$($params)*] [] [] bounds $($tail)*
+        }
+    };
+    (
+        @parse_body [$($invoke:tt)+] [trait $($header:tt)+] [$($params:tt)*] $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_supertraits [$($invoke)+] [$($header)+ [$($params)*]] [] $($tail)*
+        }
+    };
+    (
+        @parse_body [$($invoke:tt)+] [$($header:tt)+] [$($params:tt)*] ( $($fields:tt)* ) $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_where [$($invoke)+] [$($header)+] [$($params)*] [( $($fields)* )] [] $($tail)*
+        }
+    };
+    (
+        @parse_body [$($invoke:tt)+] [$($header:tt)+] [$($params:tt)*] $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_where [$($invoke)+] [$($header)+] [$($params)*] [] [] $($tail)*
+        }
+    };
+    (
+        @parse_where [$($invoke:tt)+] [$($header:tt)+] [$($params:tt)*] [$($fields:tt)*] [$($where:tt)*] ;
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_fields [$($invoke)+] [$($header)+] [$($params)*] [$($where)*] $($fields)* ;
+        }
+    };
+    (
+        @parse_where [$($invoke:tt)+] [$($header:tt)+] [$($params:tt)*] [] [$($where:tt)*] { $($fields:tt)* }
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_fields [$($invoke)+] [$($header)+] [$($params)*] [$($where)*] { $($fields)* }
+        }
+    };
+    (
+        @parse_where [$($invoke:tt)+] [trait_fn $($header:tt)+] [$($params:tt)*] [$($fields:tt)*] [$($where:tt)*]
+        ; $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_fields [$($invoke)+] [trait_fn $($header)+] [$($params)*] [$($where)*] $($fields)* ; $($tail)*
+        }
+    };
+    (
+        @parse_where [$($invoke:tt)+] [trait_fn $($header:tt)+] [$($params:tt)*] [$($fields:tt)*] [$($where:tt)*]
+        { $($body:tt)* } $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_fields [$($invoke)+] [trait_fn $($header)+] [$($params)*] [$($where)*] $($fields)* { $($body)* } $($tail)*
+        }
+    };
+    (
+        @parse_where [$($invoke:tt)+] [$($header:tt)+] [$($params:tt)*] [$($fields:tt)*] [$($where:tt)*] $token:tt $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_where [$($invoke)+] [$($header)+] [$($params)*] [$($fields)*] [$($where)* $token] $($tail)*
+        }
+    };
+    (
+        @parse_fields [$($invoke:tt)+] [struct [$vis:vis] $name:ident] [$($params:tt)*] [$(where $($where:tt)*)?]
+        ( $($(#[$($field_attrs:tt)*])* $field_vis:vis $field_ty:ty),* $(,)? ) ;
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_done [$($invoke)+]
+            kind: struct,
+            vis: [$vis],
+            name: $name,
+            generics: [$($params)*],
+            where: [$($($where)*)?],
+            fields: tuple [$({ attrs: [$(#[$($field_attrs)*])*], vis: [$field_vis], ty: [$field_ty] })*]
+        }
+    };
+    (
+        @parse_fields [$($invoke:tt)+] [struct [$vis:vis] $name:ident] [$($params:tt)*] [$(where $($where:tt)*)?]
+        ;
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_done [$($invoke)+]
+            kind: struct,
+            vis: [$vis],
+            name: $name,
+            generics: [$($params)*],
+            where: [$($($where)*)?],
+            fields: unit []
+        }
+    };
+    (
+        @parse_fields [$($invoke:tt)+] [enum [$vis:vis] $name:ident] [$($params:tt)*] [$(where $($where:tt)*)?]
+        { $($variants:tt)* }
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_variants [$($invoke)+] [[$vis] $name [$($params)*] [$($($where)*)?]] $($variants)*
+        }
+    };
+    (
+        @parse_fields [$($invoke:tt)+] [$kind:ident [$vis:vis] $name:ident] [$($params:tt)*] [$(where $($where:tt)*)?]
+        { $($(#[$($field_attrs:tt)*])* $field_vis:vis $field:ident : $field_ty:ty),* $(,)? }
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_done [$($invoke)+]
+            kind: $kind,
+            vis: [$vis],
+            name: $name,
+            generics: [$($params)*],
+            where: [$($($where)*)?],
+            fields: named [$({ attrs: [$(#[$($field_attrs)*])*], vis: [$field_vis], name: $field, ty: [$field_ty] })*]
+        }
+    };
+    (
+        @parse_fields [$($invoke:tt)+] [trait_where $($header:tt)+] [$($params:tt)*] [where $($where:tt)*] { $($items:tt)* }
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait [$($invoke)+] [$($header)+] [$($where)*] { $($items)* }
+        }
+    };
+    (
+        @parse_fields
+        [@trait_fn $invoke:tt $trait:tt $items:tt] [trait_fn $attrs:tt $safety:tt $name:ident] [$($params:tt)*]
+        [$(-> $ret:ty)? $(where $($where:tt)*)?] ( $($args:tt)* ) ; $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_receiver
+            [$invoke $trait $items [$($tail)*] $attrs $safety $name [$($params)*] [$($ret)?] [$($($where)*)?] []]
+            $($args)*
+        }
+    };
+    (
+        @parse_fields
+        [@trait_fn $invoke:tt $trait:tt $items:tt] [trait_fn $attrs:tt $safety:tt $name:ident] [$($params:tt)*]
+        [$(-> $ret:ty)? $(where $($where:tt)*)?] ( $($args:tt)* ) { $($body:tt)* } $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_receiver
+            [$invoke $trait $items [$($tail)*] $attrs $safety $name [$($params)*] [$($ret)?] [$($($where)*)?] [{ $($body)* }]]
+            $($args)*
+        }
+    };
+    (
+        @parse_fields [$ctx:tt $cfg:tt [$($macro_derive:tt)+] $($invoke:tt)*] [$kind:ident [$vis:vis] $name:ident] $($tail:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "option `@parsed` for macro derive `", ::core::stringify!($($macro_derive)+), "!` ",
+            "cannot parse fields of `", ::core::stringify!($name), "`"
+        ));
+    };
+    (
+        @parse_variants [$($invoke:tt)+] [[$vis:vis] $name:ident [$($params:tt)*] [$($where:tt)*]]
+        $(
+            $(#[$($attrs:tt)*])* $variant:ident
+            $(( $($(#[$($tuple_attrs:tt)*])* $tuple_ty:ty),* $(,)? ))?
+            $({ $($(#[$($named_attrs:tt)*])* $named:ident : $named_ty:ty),* $(,)? })?
+            $(= $discriminant:expr)?
+        ),* $(,)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_variant_shapes [$($invoke)+]
+            kind: enum,
+            vis: [$vis],
+            name: $name,
+            generics: [$($params)*],
+            where: [$($where)*],
+            variants: [$({
+                attrs: [$(#[$($attrs)*])*],
+                name: $variant,
+                fields: [
+                    $(tuple [$({ attrs: [$(#[$($tuple_attrs)*])*], vis: [], ty: [$tuple_ty] })*])?
+                    $(named [$({ attrs: [$(#[$($named_attrs)*])*], vis: [], name: $named, ty: [$named_ty] })*])?
+                    unit []
+                ],
+                discriminant: [$($discriminant)?]
+            })*]
+        }
+    };
+    (
+        @parse_variants [$ctx:tt $cfg:tt [$($macro_derive:tt)+] $($invoke:tt)*] [[$vis:vis] $name:ident $($header:tt)*]
+        $($variants:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "option `@parsed` for macro derive `", ::core::stringify!($($macro_derive)+), "!` ",
+            "cannot parse variants of `", ::core::stringify!($name), "`"
+        ));
+    };
+    (
+        @parse_variant_shapes [$($invoke:tt)+]
+        kind: enum,
+        vis: $vis:tt,
+        name: $name:ident,
+        generics: $generics:tt,
+        where: $where:tt,
+        variants: [$({
+            attrs: $attrs:tt,
+            name: $variant:ident,
+            fields: [$shape:ident $fields:tt $($other_shapes:tt)*],
+            discriminant: $discriminant:tt
+        })*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_done [$($invoke)+]
+            kind: enum,
+            vis: $vis,
+            name: $name,
+            generics: $generics,
+            where: $where,
+            variants: [$({
+                attrs: $attrs,
+                name: $variant,
+                fields: $shape $fields,
+                discriminant: $discriminant
+            })*]
+        }
+    };
+    (
+        @parse_supertraits $invoke:tt $trait:tt [] : $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_supertraits $invoke $trait [] $($tail)*
+        }
+    };
+    (
+        @parse_supertraits $invoke:tt [$($trait:tt)+] [$($bounds:tt)*] { $($items:tt)* }
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait $invoke [$($trait)+ [$($bounds)*]] [] { $($items)* }
+        }
+    };
+    (
+        @parse_supertraits [$($invoke:tt)+] [$($trait:tt)+] [$($bounds:tt)*] where $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_where [$($invoke)+] [trait_where $($trait)+ [$($bounds)*]] [] [] [where] $($tail)*
+        }
+    };
+    (
+        @parse_supertraits $invoke:tt $trait:tt [$($bounds:tt)*] $token:tt $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_supertraits $invoke $trait [$($bounds)* $token] $($tail)*
+        }
+    };
+    (
+        @parse_trait [$($invoke:tt)+] [[$vis:vis] [$($safety:tt)*] $name:ident [$($params:tt)*] [$($bounds:tt)*]]
+        [$($where:tt)*] { $($items:tt)* }
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_items [$($invoke)+]
+            [
+                kind: trait,
+                vis: [$vis],
+                safety: [$($safety)*],
+                name: $name,
+                generics: [$($params)*],
+                where: [$($where)*],
+                supertraits: [$($bounds)*],
+            ]
+            []
+            $($items)*
+        }
+    };
+    (
+        @parse_trait_items [$($invoke:tt)+] [$($trait:tt)*] [$($items:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_done [$($invoke)+]
+            $($trait)*
+            items: [$($items)*]
+        }
+    };
+    (
+        @parse_trait_items $invoke:tt $trait:tt [$($items:tt)*]
+        $(#[$($attrs:tt)*])* type $name:ident $(: $bound:path)? ; $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_items $invoke $trait
+            [$($items)* { attrs: [$(#[$($attrs)*])*], kind: type, name: $name, generics: [], bounds: [$($bound)?], where: [] }]
+            $($tail)*
+        }
+    };
+    (
+        @parse_trait_items $invoke:tt $trait:tt $items:tt
+        $(#[$($attrs:tt)*])* type $name:ident < $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_generics [@trait_type $invoke $trait $items] [trait_type [$(#[$($attrs)*])*] $name] < $($tail)*
+        }
+    };
+    (
+        @parse_trait_items $invoke:tt $trait:tt $items:tt
+        $(#[$($attrs:tt)*])* type $name:ident $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_type $invoke $trait $items [$(#[$($attrs)*])*] $name [] [] [] bounds $($tail)*
+        }
+    };
+    (
+        @parse_trait_items $invoke:tt $trait:tt [$($items:tt)*]
+        $(#[$($attrs:tt)*])* const $name:ident : $ty:ty $(= $default:expr)? ; $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_items $invoke $trait
+            [$($items)* { attrs: [$(#[$($attrs)*])*], kind: const, name: $name, ty: [$ty], default: [$($default)?] }]
+            $($tail)*
+        }
+    };
+    (
+        @parse_trait_items $invoke:tt $trait:tt $items:tt
+        $(#[$($attrs:tt)*])* unsafe fn $name:ident ( $($args:tt)* ) $(-> $ret:ty)? ; $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_receiver [$invoke $trait $items [$($tail)*] [$(#[$($attrs)*])*] [unsafe] $name [] [$($ret)?] [] []] $($args)*
+        }
+    };
+    (
+        @parse_trait_items $invoke:tt $trait:tt $items:tt
+        $(#[$($attrs:tt)*])* unsafe fn $name:ident ( $($args:tt)* ) $(-> $ret:ty)? { $($body:tt)* } $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_receiver
+            [$invoke $trait $items [$($tail)*] [$(#[$($attrs)*])*] [unsafe] $name [] [$($ret)?] [] [{ $($body)* }]]
+            $($args)*
+        }
+    };
+    (
+        @parse_trait_items $invoke:tt $trait:tt $items:tt
+        $(#[$($attrs:tt)*])* fn $name:ident ( $($args:tt)* ) $(-> $ret:ty)? ; $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_receiver [$invoke $trait $items [$($tail)*] [$(#[$($attrs)*])*] [] $name [] [$($ret)?] [] []] $($args)*
+        }
+    };
+    (
+        @parse_trait_items $invoke:tt $trait:tt $items:tt
+        $(#[$($attrs:tt)*])* fn $name:ident ( $($args:tt)* ) $(-> $ret:ty)? { $($body:tt)* } $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_receiver
+            [$invoke $trait $items [$($tail)*] [$(#[$($attrs)*])*] [] $name [] [$($ret)?] [] [{ $($body)* }]]
+            $($args)*
+        }
+    };
+    (
+        @parse_trait_items $invoke:tt $trait:tt $items:tt
+        $(#[$($attrs:tt)*])* unsafe fn $name:ident $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_generics [@trait_fn $invoke $trait $items] [trait_fn [$(#[$($attrs)*])*] [unsafe] $name] $($tail)*
+        }
+    };
+    (
+        @parse_trait_items $invoke:tt $trait:tt $items:tt
+        $(#[$($attrs:tt)*])* fn $name:ident $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_generics [@trait_fn $invoke $trait $items] [trait_fn [$(#[$($attrs)*])*] [] $name] $($tail)*
+        }
+    };
+    (
+        @parse_trait_items [$ctx:tt $cfg:tt [$($macro_derive:tt)+] $($invoke:tt)*] [kind: trait, vis: $vis:tt, safety: $safety:tt, name: $name:ident, $($trait:tt)*]
+        $($tail:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "option `@parsed` for macro derive `", ::core::stringify!($($macro_derive)+), "!` ",
+            "cannot parse associated items of `", ::core::stringify!($name), "`"
+        ));
+    };
+    (
+        @parse_trait_type $invoke:tt $trait:tt [$($items:tt)*] $attrs:tt $name:ident $generics:tt $bounds:tt $where:tt $mode:ident
+        ; $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_items $invoke $trait
+            [$($items)* { attrs: $attrs, kind: type, name: $name, generics: $generics, bounds: $bounds, where: $where }]
+            $($tail)*
+        }
+    };
+    (
+        @parse_trait_type $invoke:tt $trait:tt $items:tt $attrs:tt $name:ident $generics:tt [] [] bounds : $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_type $invoke $trait $items $attrs $name $generics [] [] bounds $($tail)*
+        }
+    };
+    (
+        @parse_trait_type $invoke:tt $trait:tt $items:tt $attrs:tt $name:ident $generics:tt $bounds:tt [] bounds where $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_type $invoke $trait $items $attrs $name $generics $bounds [] where $($tail)*
+        }
+    };
+    (
+        @parse_trait_type $invoke:tt $trait:tt $items:tt $attrs:tt $name:ident $generics:tt [$($bounds:tt)*] $where:tt bounds
+        $token:tt $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_type $invoke $trait $items $attrs $name $generics [$($bounds)* $token] $where bounds $($tail)*
+        }
+    };
+    (
+        @parse_trait_type $invoke:tt $trait:tt $items:tt $attrs:tt $name:ident $generics:tt $bounds:tt [$($where:tt)*] where
+        $token:tt $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_type $invoke $trait $items $attrs $name $generics $bounds [$($where)* $token] where $($tail)*
+        }
+    };
+    (
+        @parse_receiver
+        [$invoke:tt $trait:tt [$($items:tt)*] [$($tail:tt)*] $attrs:tt $safety:tt $name:ident $generics:tt $ret:tt $where:tt $default:tt]
+        & $($lifetime:lifetime)? self $(, $($arg:ident : $arg_ty:ty),* $(,)?)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_items $invoke $trait
+            [$($items)* {
+                attrs: $attrs,
+                kind: fn,
+                safety: $safety,
+                name: $name,
+                generics: $generics,
+                receiver: ref [& $($lifetime)? self],
+                args: [$($({ pat: [$arg], ty: [$arg_ty] })*)?],
+                ret: $ret,
+                where: $where,
+                default: $default
+            }]
+            $($tail)*
+        }
+    };
+    (
+        @parse_receiver
+        [$invoke:tt $trait:tt [$($items:tt)*] [$($tail:tt)*] $attrs:tt $safety:tt $name:ident $generics:tt $ret:tt $where:tt $default:tt]
+        & $($lifetime:lifetime)? mut self $(, $($arg:ident : $arg_ty:ty),* $(,)?)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_items $invoke $trait
+            [$($items)* {
+                attrs: $attrs,
+                kind: fn,
+                safety: $safety,
+                name: $name,
+                generics: $generics,
+                receiver: mut [& $($lifetime)? mut self],
+                args: [$($({ pat: [$arg], ty: [$arg_ty] })*)?],
+                ret: $ret,
+                where: $where,
+                default: $default
+            }]
+            $($tail)*
+        }
+    };
+    (
+        @parse_receiver
+        [$invoke:tt $trait:tt [$($items:tt)*] [$($tail:tt)*] $attrs:tt $safety:tt $name:ident $generics:tt $ret:tt $where:tt $default:tt]
+        self : $self_ty:ty $(, $($arg:ident : $arg_ty:ty),* $(,)?)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_items $invoke $trait
+            [$($items)* {
+                attrs: $attrs,
+                kind: fn,
+                safety: $safety,
+                name: $name,
+                generics: $generics,
+                receiver: typed [self: $self_ty],
+                args: [$($({ pat: [$arg], ty: [$arg_ty] })*)?],
+                ret: $ret,
+                where: $where,
+                default: $default
+            }]
+            $($tail)*
+        }
+    };
+    (
+        @parse_receiver
+        [$invoke:tt $trait:tt [$($items:tt)*] [$($tail:tt)*] $attrs:tt $safety:tt $name:ident $generics:tt $ret:tt $where:tt $default:tt]
+        mut self : $self_ty:ty $(, $($arg:ident : $arg_ty:ty),* $(,)?)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_items $invoke $trait
+            [$($items)* {
+                attrs: $attrs,
+                kind: fn,
+                safety: $safety,
+                name: $name,
+                generics: $generics,
+                receiver: typed [mut self: $self_ty],
+                args: [$($({ pat: [$arg], ty: [$arg_ty] })*)?],
+                ret: $ret,
+                where: $where,
+                default: $default
+            }]
+            $($tail)*
+        }
+    };
+    (
+        @parse_receiver
+        [$invoke:tt $trait:tt [$($items:tt)*] [$($tail:tt)*] $attrs:tt $safety:tt $name:ident $generics:tt $ret:tt $where:tt $default:tt]
+        self $(, $($arg:ident : $arg_ty:ty),* $(,)?)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_items $invoke $trait
+            [$($items)* {
+                attrs: $attrs,
+                kind: fn,
+                safety: $safety,
+                name: $name,
+                generics: $generics,
+                receiver: value [self],
+                args: [$($({ pat: [$arg], ty: [$arg_ty] })*)?],
+                ret: $ret,
+                where: $where,
+                default: $default
+            }]
+            $($tail)*
+        }
+    };
+    (
+        @parse_receiver
+        [$invoke:tt $trait:tt [$($items:tt)*] [$($tail:tt)*] $attrs:tt $safety:tt $name:ident $generics:tt $ret:tt $where:tt $default:tt]
+        mut self $(, $($arg:ident : $arg_ty:ty),* $(,)?)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_items $invoke $trait
+            [$($items)* {
+                attrs: $attrs,
+                kind: fn,
+                safety: $safety,
+                name: $name,
+                generics: $generics,
+                receiver: value [mut self],
+                args: [$($({ pat: [$arg], ty: [$arg_ty] })*)?],
+                ret: $ret,
+                where: $where,
+                default: $default
+            }]
+            $($tail)*
+        }
+    };
+    (
+        @parse_receiver
+        [$invoke:tt $trait:tt [$($items:tt)*] [$($tail:tt)*] $attrs:tt $safety:tt $name:ident $generics:tt $ret:tt $where:tt $default:tt]
+        $($arg:ident : $arg_ty:ty),* $(,)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_items $invoke $trait
+            [$($items)* {
+                attrs: $attrs,
+                kind: fn,
+                safety: $safety,
+                name: $name,
+                generics: $generics,
+                receiver: none [],
+                args: [$({ pat: [$arg], ty: [$arg_ty] })*],
+                ret: $ret,
+                where: $where,
+                default: $default
+            }]
+            $($tail)*
+        }
+    };
+    (
+        @parse_receiver $fn:tt & $($lifetime:lifetime)? self $(, $($args:tt)*)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_args $fn [ref [& $($lifetime)? self]] [] $($($args)*)?
+        }
+    };
+    (
+        @parse_receiver $fn:tt & $($lifetime:lifetime)? mut self $(, $($args:tt)*)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_args $fn [mut [& $($lifetime)? mut self]] [] $($($args)*)?
+        }
+    };
+    (
+        @parse_receiver $fn:tt self : $self_ty:ty $(, $($args:tt)*)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_args $fn [typed [self: $self_ty]] [] $($($args)*)?
+        }
+    };
+    (
+        @parse_receiver $fn:tt mut self : $self_ty:ty $(, $($args:tt)*)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_args $fn [typed [mut self: $self_ty]] [] $($($args)*)?
+        }
+    };
+    (
+        @parse_receiver $fn:tt self $(, $($args:tt)*)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_args $fn [value [self]] [] $($($args)*)?
+        }
+    };
+    (
+        @parse_receiver $fn:tt mut self $(, $($args:tt)*)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_args $fn [value [mut self]] [] $($($args)*)?
+        }
+    };
+    (
+        @parse_receiver $fn:tt $($args:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_args $fn [none []] [] $($args)*
+        }
+    };
+    (
+        @parse_args
+        [$invoke:tt $trait:tt [$($items:tt)*] [$($tail:tt)*] $attrs:tt $safety:tt $name:ident $generics:tt $ret:tt $where:tt $default:tt]
+        [$receiver:ident $self:tt] [$($args:tt)*] $($arg:ident : $arg_ty:ty),* $(,)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_trait_items $invoke $trait
+            [$($items)* {
+                attrs: $attrs,
+                kind: fn,
+                safety: $safety,
+                name: $name,
+                generics: $generics,
+                receiver: $receiver $self,
+                args: [$($args)* $({ pat: [$arg], ty: [$arg_ty] })*],
+                ret: $ret,
+                where: $where,
+                default: $default
+            }]
+            $($tail)*
+        }
+    };
+    (
+        @parse_args $fn:tt $receiver:tt $args:tt $token:tt $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_arg_pat $fn $receiver $args [$token] $($tail)*
+        }
+    };
+    (
+        @parse_arg_pat $fn:tt $receiver:tt [$($args:tt)*] [$($pat:tt)+] : $arg_ty:ty $(, $($tail:tt)*)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_args $fn $receiver [$($args)* { pat: [$($pat)+], ty: [$arg_ty] }] $($($tail)*)?
+        }
+    };
+    (
+        @parse_arg_pat $fn:tt $receiver:tt $args:tt [$($pat:tt)+] $token:tt $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @parse_arg_pat $fn $receiver $args [$($pat)+ $token] $($tail)*
+        }
+    };
+    (
+        @parse_arg_pat
+        [
+            [$ctx:tt $cfg:tt [$($macro_derive:tt)+] $($invoke:tt)*]
+            [kind: trait, vis: $vis:tt, safety: $trait_safety:tt, name: $trait_name:ident, $($trait:tt)*]
+            $items:tt $tail:tt $attrs:tt $safety:tt $name:ident $($fn:tt)*
+        ]
+        $receiver:tt $args:tt $pat:tt
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "option `@parsed` for macro derive `", ::core::stringify!($($macro_derive)+), "!` ",
+            "cannot parse arguments of `", ::core::stringify!($trait_name), "::", ::core::stringify!($name), "`"
+        ));
+    };
+    (
+        @parse_done [$ctx:tt $cfg:tt $macro_derive:tt $macro_derive_options:tt [$($sections:tt)*] $($invoke:tt)+]
+        $($parsed:tt)+
+    ) => {
+        $crate::macro_attr_impl! {
+            @invoke $ctx $cfg $macro_derive $macro_derive_options [$($sections)* @parsed { $($parsed)+ }] $($invoke)+
+        }
+    };
+    (@recursion_limit 1 $($invoke:tt)+) => {
+        $crate::macro_attr_impl! { @invoke ([_]) $($invoke)+ }
+    };
+    (@recursion_limit 2 $($invoke:tt)+) => {
+        $crate::macro_attr_impl! { @invoke ([_ _]) $($invoke)+ }
+    };
+    (@recursion_limit 3 $($invoke:tt)+) => {
+        $crate::macro_attr_impl! { @invoke ([_ _ _]) $($invoke)+ }
+    };
+    (@recursion_limit 4 $($invoke:tt)+) => {
+        $crate::macro_attr_impl! { @invoke ([_ _ _ _]) $($invoke)+ }
+    };
+    (@recursion_limit 5 $($invoke:tt)+) => {
+        $crate::macro_attr_impl! { @invoke ([_ _ _ _ _]) $($invoke)+ }
+    };
+    (@recursion_limit 6 $($invoke:tt)+) => {
+        $crate::macro_attr_impl! { @invoke ([_ _ _ _ _ _]) $($invoke)+ }
+    };
+    (@recursion_limit 7 $($invoke:tt)+) => {
+        $crate::macro_attr_impl! { @invoke ([_ _ _ _ _ _ _]) $($invoke)+ }
+    };
+    (@recursion_limit 8 $($invoke:tt)+) => {
+        $crate::macro_attr_impl! { @invoke ([_ _ _ _ _ _ _ _]) $($invoke)+ }
+    };
+    (
+        @recursion_limit $limit:tt [$($cfg:tt)*] [$($macro_derive:tt)+] $($invoke:tt)+
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "invalid recursion limit `", ::core::stringify!($limit), "` for macro derive `",
+            ::core::stringify!($($macro_derive)+), "!`, expected an integer from 1 to 8"
+        ));
+    };
+    (
+        @strip_item [$($attrs:tt)*] $vis:vis struct $($tail:tt)+
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_header [$($attrs)* $vis struct] [] tuple $($tail)+
+        }
+    };
+    (
+        @strip_item [$($attrs:tt)*] $vis:vis union $($tail:tt)+
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_header [$($attrs)* $vis union] [] named $($tail)+
+        }
+    };
+    (
+        @strip_item [$($attrs:tt)*] $vis:vis enum $($tail:tt)+
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_header [$($attrs)* $vis enum] [] variants $($tail)+
+        }
+    };
+    (
+        @strip_item [$($attrs:tt)*] $($it:tt)+
+    ) => {
+        $crate::macro_attr_impl! {
+            @as_item
+            $($attrs)*
+            $($it)+
+        }
+    };
+    (
+        @strip_header [$($head:tt)*] [] tuple ( $($fields:tt)* ) $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_tuple [@strip_done [$($head)*] tuple [$($tail)*]] $($fields)*
+        }
+    };
+    (
+        @strip_header [$($head:tt)*] [] tuple where $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_header [$($head)* where] [] named $($tail)*
+        }
+    };
+    (
+        @strip_header [$($head:tt)*] [] variants { $($variants:tt)* } $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_variants [@strip_done [$($head)*] named [$($tail)*]] $($variants)*
+        }
+    };
+    (
+        @strip_header [$($head:tt)*] [] $mode:ident { $($fields:tt)* } $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_named [@strip_done [$($head)*] named [$($tail)*]] $($fields)*
+        }
+    };
+    (
+        @strip_header [$($head:tt)*] [$($depth:tt)*] $mode:ident << $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_header [$($head)* <<] [< < $($depth)*] $mode $($tail)*
+        }
+    };
+    (
+        @strip_header [$($head:tt)*] [$($depth:tt)*] $mode:ident < $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_header [$($head)* <] [< $($depth)*] $mode $($tail)*
+        }
+    };
+    (
+        @strip_header [$($head:tt)*] [< $($depth:tt)*] $mode:ident >= $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_header [$($head)* >] [$($depth)*] $mode = $($tail)*
+        }
+    };
+    (
+        @strip_header [$($head:tt)*] [< < $($depth:tt)*] $mode:ident >>= $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_header [$($head)* >>] [$($depth)*] $mode = $($tail)*
+        }
+    };
+    (
+        @strip_header [$($head:tt)*] [< $($depth:tt)*] $mode:ident > $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_header [$($head)* >] [$($depth)*] $mode $($tail)*
+        }
+    };
+    (
+        @strip_header [$($head:tt)*] [< < $($depth:tt)*] $mode:ident >> $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_header [$($head)* >>] [$($depth)*] $mode $($tail)*
+        }
+    };
+    (
+        @strip_header [$($head:tt)*] [$($depth:tt)*] $mode:ident $token:tt $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_header [$($head)* $token] [$($depth)*] $mode $($tail)*
+        }
+    };
+    (
+        @strip_header [$($head:tt)*] [$($depth:tt)*] $mode:ident
+    ) => {
+        $crate::macro_attr_impl! {
+            @as_item
+            $($head)*
+        }
+    };
+    (
+        @strip_named [$($cont:tt)*]
+        $($(#[$($field_attrs:tt)*])* $field_vis:vis $field:ident : $field_ty:ty),* $(,)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_records [$($cont)*] []
+            $([$(# [$($field_attrs)*])* @ [$field_vis $field: $field_ty]])*
+        }
+    };
+    (
+        @strip_tuple [$($cont:tt)*]
+        $($(#[$($field_attrs:tt)*])* $field_vis:vis $field_ty:ty),* $(,)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_records [$($cont)*] []
+            $([$(# [$($field_attrs)*])* @ [$field_vis $field_ty]])*
+        }
+    };
+    (
+        @strip_variants [$($cont:tt)*]
+        $(
+            $(#[$($variant_attrs:tt)*])* $variant:ident
+            $(( $($(#[$($tuple_attrs:tt)*])* $tuple_vis:vis $tuple_ty:ty),* $(,)? ))?
+            $({ $($(#[$($named_attrs:tt)*])* $named_vis:vis $named:ident : $named_ty:ty),* $(,)? })?
+            $(= $discriminant:expr)?
+        ),* $(,)?
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_records [$($cont)*] []
+            $([
+                $(# [$($variant_attrs)*])*
+                $($($(! [$($tuple_attrs)*])*)*)?
+                $($($(! [$($named_attrs)*])*)*)?
+                @ [
+                    $variant
+                    $(($($tuple_vis $tuple_ty),*))?
+                    $({$($named_vis $named: $named_ty),*})?
+                    $(= $discriminant)?
+                ]
+                $variant
+                $(( $([$(# [$($tuple_attrs)*])* @ [$tuple_vis $tuple_ty]])* ))?
+                $({ $([$(# [$($named_attrs)*])* @ [$named_vis $named: $named_ty]])* })?
+                [$(= $discriminant)?]
+            ])*
+        }
+    };
+    (
+        @strip_named [$($cont:tt)*] $($input:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "cannot parse fields or variants of an item with macro derives: `", ::core::stringify!($($input)*), "`"
+        ));
+    };
+    (
+        @strip_tuple [$($cont:tt)*] $($input:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "cannot parse fields or variants of an item with macro derives: `", ::core::stringify!($($input)*), "`"
+        ));
+    };
+    (
+        @strip_variants [$($cont:tt)*] $($input:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "cannot parse fields or variants of an item with macro derives: `", ::core::stringify!($($input)*), "`"
+        ));
+    };
+    (
+        @strip_records [$($cont:tt)*] [$($out:tt)*]
+        $([$(# [$attr:ident = $value:tt])* @ [$($plain:tt)*] $($plain_tail:tt)*])*
+    ) => {
+        $crate::macro_attr_impl! {
+            $($cont)* [$($out)* $($(#[$attr = $value])* $($plain)*,)*]
+        }
+    };
+    (
+        @strip_records $cont:tt [$($out:tt)*]
+        $([@ [$($plain:tt)*] $($plain_tail:tt)*])*
+        [# [macro_attr $($helper:tt)*] $($record:tt)*]
+        $($records:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_records $cont [$($out)* $($($plain)*,)*] [$($record)*] $($records)*
+        }
+    };
+    (
+        @strip_records $cont:tt [$($out:tt)*]
+        $([@ [$($plain:tt)*] $($plain_tail:tt)*])*
+        [# [$($helper_root:ident)? $(:: $helper_segment:ident)* ! $($helper:tt)*] $($record:tt)*]
+        $($records:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_records $cont [$($out)* $($($plain)*,)*] [$($record)*] $($records)*
+        }
+    };
+    (
+        @strip_records $cont:tt [$($out:tt)*]
+        $([@ [$($plain:tt)*] $($plain_tail:tt)*])*
+        [$(! [$($field_attrs:tt)*])+ @ $variant_plain:tt $variant:ident ( $($fields:tt)* ) [$($discriminant:tt)*]]
+        $($records:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_records
+            [@strip_variant [$cont [$($out)* $($($plain)*,)*] [$($records)*]] $variant tuple [$($discriminant)*]]
+            []
+            $($fields)*
+        }
+    };
+    (
+        @strip_records $cont:tt [$($out:tt)*]
+        $([@ [$($plain:tt)*] $($plain_tail:tt)*])*
+        [$(! [$($field_attrs:tt)*])+ @ $variant_plain:tt $variant:ident { $($fields:tt)* } [$($discriminant:tt)*]]
+        $($records:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_records
+            [@strip_variant [$cont [$($out)* $($($plain)*,)*] [$($records)*]] $variant named [$($discriminant)*]]
+            []
+            $($fields)*
+        }
+    };
+    (
+        @strip_records $cont:tt [$($out:tt)*]
+        $([@ [$($plain:tt)*] $($plain_tail:tt)*])*
+        [$(# [$attr0:ident = $value0:tt])+ @ [$($plain0:tt)*] $($plain_tail0:tt)*]
+        [$(# [$attr1:ident = $value1:tt])* @ [$($plain1:tt)*] $($plain_tail1:tt)*]
+        [$(# [$attr2:ident = $value2:tt])* @ [$($plain2:tt)*] $($plain_tail2:tt)*]
+        [$(# [$attr3:ident = $value3:tt])* @ [$($plain3:tt)*] $($plain_tail3:tt)*]
+        [$(# [$attr4:ident = $value4:tt])* @ [$($plain4:tt)*] $($plain_tail4:tt)*]
+        [$(# [$attr5:ident = $value5:tt])* @ [$($plain5:tt)*] $($plain_tail5:tt)*]
+        [$(# [$attr6:ident = $value6:tt])* @ [$($plain6:tt)*] $($plain_tail6:tt)*]
+        [$(# [$attr7:ident = $value7:tt])* @ [$($plain7:tt)*] $($plain_tail7:tt)*]
+        $($records:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_records $cont
+            [
+                $($out)*
+                $($($plain)*,)*
+                $(#[$attr0 = $value0])* $($plain0)*,
+                $(#[$attr1 = $value1])* $($plain1)*,
+                $(#[$attr2 = $value2])* $($plain2)*,
+                $(#[$attr3 = $value3])* $($plain3)*,
+                $(#[$attr4 = $value4])* $($plain4)*,
+                $(#[$attr5 = $value5])* $($plain5)*,
+                $(#[$attr6 = $value6])* $($plain6)*,
+                $(#[$attr7 = $value7])* $($plain7)*,
+            ]
+            $($records)*
+        }
+    };
+    (
+        @strip_records $cont:tt [$($out:tt)*]
+        $([@ [$($plain:tt)*] $($plain_tail:tt)*])*
+        [# [$($attr:tt)*] $($record:tt)*]
+        $($records:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_records $cont [$($out)* $($($plain)*,)* #[$($attr)*]] [$($record)*] $($records)*
+        }
+    };
+    (
+        @strip_records [$($cont:tt)*] [$($out:tt)*]
+        $([@ [$($plain:tt)*] $($plain_tail:tt)*])*
+    ) => {
+        $crate::macro_attr_impl! {
+            $($cont)* [$($out)* $($($plain)*,)*]
+        }
+    };
+    (
+        @strip_variant [$cont:tt [$($out:tt)*] [$($records:tt)*]] $variant:ident tuple [$($discriminant:tt)*]
+        [$($fields:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_records $cont [$($out)* $variant ( $($fields)* ) $($discriminant)*,] $($records)*
+        }
+    };
+    (
+        @strip_variant [$cont:tt [$($out:tt)*] [$($records:tt)*]] $variant:ident named [$($discriminant:tt)*]
+        [$($fields:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_records $cont [$($out)* $variant { $($fields)* } $($discriminant)*,] $($records)*
+        }
+    };
+    (
+        @strip_done [$($head:tt)*] tuple [$($tail:tt)*] [$($fields:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @as_item
+            $($head)* ( $($fields)* ) $($tail)*
+        }
+    };
+    (
+        @strip_done [$($head:tt)*] named [$($tail:tt)*] [$($fields:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @as_item
+            $($head)* { $($fields)* } $($tail)*
+        }
+    };
+    (
+        @bundles []
+    ) => {
+    };
+    (
+        @bundles [$($bundle:tt)+]
+    ) => {
+        $crate::macro_attr_impl! {
+            @bundle_def ($) $($bundle)+
+        }
+    };
+    (
+        @bundles [$($bundle:tt)*] ; $($bundles:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @bundle_def ($) $($bundle)*
+        }
+        $crate::macro_attr_impl! {
+            @bundles [] $($bundles)*
+        }
+    };
+    (
+        @bundles [$($bundle:tt)*] $token:tt $($bundles:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @bundles [$($bundle)* $token] $($bundles)*
+        }
+    };
+    (
+        @bundle_def ($d:tt) $(#[$($attrs:tt)*])* pub ($($restriction:tt)*) $name:ident = $($derives:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @bundle_def ($d) $(#[$($attrs)*])* $name = $($derives)*
+        }
+        pub ($($restriction)*) use $name;
+    };
+    (
+        @bundle_def ($d:tt) $(#[$($attrs:tt)*])* pub $name:ident = $($derives:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @bundle_def ($d) $(#[$($attrs)*])* #[macro_export] $name = $($derives)*
+        }
+    };
+    (
+        @bundle_def ($d:tt) $(#[$($attrs:tt)*])* $name:ident = $($derives:tt)*
+    ) => {
+        $(#[$($attrs)*])*
+        macro_rules! $name {
+            (@macro_attr_bundle [$d($d callback:tt)+] [$d($d state:tt)*]) => {
+                $d($d callback)+! { $d($d state)* [$($derives)*] }
+            };
+            ($d($d input:tt)*) => {
+                ::core::compile_error!(::core::concat!(
+                    "derive bundle `", ::core::stringify!($name), "!` should be used as `@bundle ",
+                    ::core::stringify!($name), "!`"
+                ));
+            };
+        }
+    };
+    (
+        @bundle_def ($d:tt) $($bundle:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "malformed derive bundle `", ::core::stringify!($($bundle)*), "`, expected `Name = derives...`"
+        ));
+    };
+    (
+        @args_defs []
+    ) => {
+    };
+    (
+        @args_defs [$($parser:tt)+]
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "malformed argument parser `", ::core::stringify!($($parser)+), "`, expected `Name { key = [default], ... }`"
+        ));
+    };
+    (
+        @args_defs [$($parser:tt)*] { $($keys:tt)* } $($parsers:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @args_def ($) $($parser)* { $($keys)* }
+        }
+        $crate::macro_attr_impl! {
+            @args_defs [] $($parsers)*
+        }
+    };
+    (
+        @args_defs [$($parser:tt)*] $token:tt $($parsers:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @args_defs [$($parser)* $token] $($parsers)*
+        }
+    };
+    (
+        @args_def ($d:tt) $(#[$($attrs:tt)*])* pub ($($restriction:tt)*) $name:ident { $($keys:tt)* }
+    ) => {
+        $crate::macro_attr_impl! {
+            @args_def ($d) $(#[$($attrs)*])* $name { $($keys)* }
+        }
+        pub ($($restriction)*) use $name;
+    };
+    (
+        @args_def ($d:tt) $(#[$($attrs:tt)*])* pub $name:ident { $($keys:tt)* }
+    ) => {
+        $crate::macro_attr_impl! {
+            @args_def ($d) $(#[$($attrs)*])* #[macro_export] $name { $($keys)* }
+        }
+    };
+    (
+        @args_def ($d:tt) $(#[$($attrs:tt)*])* $name:ident { $($key:ident = [$($default:tt)*]),* $(,)? }
+    ) => {
+        $(#[$($attrs)*])*
+        macro_rules! $name {
+            $(
+                (
+                    @macro_attr_args_set $d callback:tt [$d($d done:tt)*] [[$key $d old:tt] $d($d map:tt)*]
+                    $key $d value:tt $d($d args:tt)*
+                ) => {
+                    $crate::macro_attr_impl! {
+                        @args_parse [$name] $d callback [$d($d done)* [$key $d value] $d($d map)*] $d($d args)*
+                    }
+                };
+            )*
+            (
+                @macro_attr_args_set $d callback:tt [$d($d done:tt)*] [$d entry:tt $d($d map:tt)*]
+                $d key:ident $d value:tt $d($d args:tt)*
+            ) => {
+                $name! {
+                    @macro_attr_args_set $d callback [$d($d done)* $d entry] [$d($d map)*] $d key $d value $d($d args)*
+                }
+            };
+            (
+                @macro_attr_args_set $d callback:tt [$d($d done:tt)*] [] $d key:ident $d value:tt $d($d args:tt)*
+            ) => {
+                ::core::compile_error!(::core::concat!(
+                    "unknown argument `", ::core::stringify!($d key), "` for `", ::core::stringify!($name), "!`, ",
+                    "expected one of: ", ::core::stringify!($($key),*)
+                ));
+            };
+            (
+                $d($d callback_root:ident)? $d(:: $d callback_segment:ident)* ! { $d($d callback_args:tt)* }
+                ($d($d args:tt)*)
+            ) => {
+                $crate::macro_attr_impl! {
+                    @args_parse [$name]
+                    [[$d($d callback_root)? $d(:: $d callback_segment)*] { $d($d callback_args)* }]
+                    [$([$key [$($default)*]])*]
+                    $d($d args)*
+                }
+            };
+        }
+    };
+    (
+        @args_def ($d:tt) $($parser:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "malformed argument parser `", ::core::stringify!($($parser)*), "`, expected `Name { key = [default], ... }`"
+        ));
+    };
+    (
+        @args_parse $parser:tt [[$($callback:tt)*] { $($callback_args:tt)* }] [$([$key:ident [$($value:tt)*]])*]
+    ) => {
+        $($callback)*! {
+            $($callback_args)*
+            { $($key: [$($value)*]),* }
+        }
+    };
+    (
+        @args_parse $parser:tt $callback:tt $map:tt $key:ident = $($args:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @args_value $parser $callback $map $key [] [] [$($args)*] $($args)*
+        }
+    };
+    (
+        @args_parse [$($parser:tt)+] $callback:tt $map:tt $key:ident ($($value:tt)*) $(, $($args:tt)*)?
+    ) => {
+        $($parser)+! {
+            @macro_attr_args_set $callback [] $map $key [$($value)*] $($($args)*)?
+        }
+    };
+    (
+        @args_parse [$($parser:tt)+] $callback:tt $map:tt $key:ident $(, $($args:tt)*)?
+    ) => {
+        $($parser)+! {
+            @macro_attr_args_set $callback [] $map $key [true] $($($args)*)?
+        }
+    };
+    (
+        @args_parse [$($parser:tt)+] $callback:tt $map:tt $($args:tt)+
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "malformed arguments `", ::core::stringify!($($args)+), "` for `", ::core::stringify!($($parser)+), "!`, ",
+            "expected `key = value`, `key(value...)` or `key`"
+        ));
+    };
+    (
+        @args_value [$($parser:tt)+] $callback:tt $map:tt $key:ident [$($value:tt)+] [$(plain)?] $args_start:tt
+        $(, $($args:tt)*)?
+    ) => {
+        $($parser)+! {
+            @macro_attr_args_set $callback [] $map $key [$($value)+] $($($args)*)?
+        }
+    };
+    (
+        @args_value [$($parser:tt)+] $callback:tt $map:tt $key:ident [] [$(plain)?] $args_start:tt $(, $($args:tt)*)?
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "missing value for argument `", ::core::stringify!($key), "` of `", ::core::stringify!($($parser)+), "!`"
+        ));
+    };
+    (
+        @args_value $parser:tt $callback:tt $map:tt $key:ident [$($value:tt)*] [plain] $args_start:tt $token:tt $($args:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @args_value $parser $callback $map $key [$($value)* $token] [plain] $args_start $($args)*
+        }
+    };
+    (
+        @args_value $parser:tt $callback:tt $map:tt $key:ident [$($value:tt)*] [_ $($depth:tt)*] [$($args:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @args_value $parser $callback $map $key [] [plain] [] $($args)*
+        }
+    };
+    (
+        @args_value $parser:tt $callback:tt $map:tt $key:ident [$($value:tt)*] [$($depth:tt)*] $args_start:tt < $($args:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @args_value $parser $callback $map $key [$($value)* <] [_ $($depth)*] $args_start $($args)*
+        }
+    };
+    (
+        @args_value $parser:tt $callback:tt $map:tt $key:ident [$($value:tt)*] [$($depth:tt)*] $args_start:tt << $($args:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @args_value $parser $callback $map $key [$($value)* <<] [_ _ $($depth)*] $args_start $($args)*
+        }
+    };
+    (
+        @args_value $parser:tt $callback:tt $map:tt $key:ident [$($value:tt)*] [_ $($depth:tt)*] $args_start:tt > $($args:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @args_value $parser $callback $map $key [$($value)* >] [$($depth)*] $args_start $($args)*
+        }
+    };
+    (
+        @args_value $parser:tt $callback:tt $map:tt $key:ident [$($value:tt)*] [_ _ $($depth:tt)*] $args_start:tt >> $($args:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @args_value $parser $callback $map $key [$($value)* >>] [$($depth)*] $args_start $($args)*
+        }
+    };
+    (
+        @args_value $parser:tt $callback:tt $map:tt $key:ident [$($value:tt)*] [_] $args_start:tt >> $($args:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @args_value $parser $callback $map $key [$($value)* >>] [] $args_start $($args)*
+        }
+    };
+    (
+        @args_value $parser:tt $callback:tt $map:tt $key:ident [$($value:tt)*] $depth:tt $args_start:tt $token:tt $($args:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @args_value $parser $callback $map $key [$($value)* $token] $depth $args_start $($args)*
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [] [] start const $param:ident $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)*] [$($ty_params)* $param,] [const $param] [] bounds $($tail)*
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [] [] start $param:tt $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)*] [$($ty_params)* $param,] [$param] [] bounds $($tail)*
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [$($param:tt)*] [] $mode:ident , $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)* $($param)*,] [$($ty_params)*] [] [] start $($tail)*
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [$($param:tt)*] [] bounds = $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)*] [$($ty_params)*] [$($param)*] [] default $($tail)*
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [$($param:tt)*] [< $($depth:tt)*] $mode:ident >= $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)*] [$($ty_params)*] [$($param)* >] [$($depth)*] $mode = $($tail)*
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [$($param:tt)*] [< < $($depth:tt)*] $mode:ident >>= $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)*] [$($ty_params)*] [$($param)* >>] [$($depth)*] $mode = $($tail)*
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [$($param:tt)*] [$($depth:tt)*] bounds << $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)*] [$($ty_params)*] [$($param)* <<] [< < $($depth)*] bounds $($tail)*
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [$($param:tt)*] [$($depth:tt)*] bounds < $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)*] [$($ty_params)*] [$($param)* <] [< $($depth)*] bounds $($tail)*
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [$($param:tt)*] [< $($depth:tt)*] bounds > $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)*] [$($ty_params)*] [$($param)* >] [$($depth)*] bounds $($tail)*
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [$($param:tt)*] [< < $($depth:tt)*] bounds >> $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)*] [$($ty_params)*] [$($param)* >>] [$($depth)*] bounds $($tail)*
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [$($param:tt)*] [$($depth:tt)*] bounds $token:tt $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)*] [$($ty_params)*] [$($param)* $token] [$($depth)*] bounds $($tail)*
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [$($param:tt)*] [$($depth:tt)*] default << $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)*] [$($ty_params)*] [$($param)*] [< < $($depth)*] default $($tail)*
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [$($param:tt)*] [$($depth:tt)*] default < $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)*] [$($ty_params)*] [$($param)*] [< $($depth)*] default $($tail)*
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [$($param:tt)*] [< $($depth:tt)*] default > $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)*] [$($ty_params)*] [$($param)*] [$($depth)*] default $($tail)*
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [$($param:tt)*] [< < $($depth:tt)*] default >> $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)*] [$($ty_params)*] [$($param)*] [$($depth)*] default $($tail)*
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [$($param:tt)*] [$($depth:tt)*] default $token:tt $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)*] [$($ty_params)*] [$($param)*] [$($depth)*] default $($tail)*
+        }
+    };
+    (
+        @split_generics [[$($callback:tt)+] { $($callback_args:tt)* } [$($where:tt)*]]
+        [$($impl_params:tt)*] [$($ty_params:tt)*] [] [] $mode:ident
+    ) => {
+        $($callback)+! {
+            $($callback_args)*
+            [$($impl_params)*] [$($ty_params)*] [$($where)*]
+        }
+    };
+    (
+        @split_generics [$($callback:tt)+] [$($impl_params:tt)*] [$($ty_params:tt)*] [$($param:tt)+] [] $mode:ident
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [$($callback)+] [$($impl_params)* $($param)+,] [$($ty_params)*] [] [] $mode
+        }
+    };
+    (
+        @forward [$($targets:tt)*]
+        kind: trait,
+        vis: $vis:tt,
+        safety: $safety:tt,
+        name: $name:ident,
+        generics: [$($params:tt)*],
+        where: [$($where:tt)*],
+        supertraits: $supertraits:tt,
+        items: [$($items:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [[$crate::macro_attr_impl] { @forward_split [[$($targets)*] $safety $name [$($items)*]] } [$($where)*]]
+            [] [] [] [] start $($params)*
+        }
+    };
+    (
+        @forward_split [$targets:tt $safety:tt $name:ident [$($items:tt)*]] $impl_params:tt [$($ty_params:tt)*] $where:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @forward_scan [$targets $safety $name [$($items)*] $impl_params [$($ty_params)*] $where [$name<$($ty_params)*>]]
+            ref [?Sized +] $($items)*
+        }
+    };
+    (
+        @forward $targets:tt kind: $kind:ident, vis: $vis:tt, name: $name:ident, $($parsed:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "`Forward!` can be derived for traits only, and `", ::core::stringify!($name), "` is ",
+            "a `", ::core::stringify!($kind), "`"
+        ));
+    };
+    (
+        @forward_scan $forward:tt $level:ident [$($unsized:tt)+]
+        {
+            attrs: $attrs:tt, kind: fn, safety: $safety:tt, name: $name:ident, generics: $generics:tt, receiver: $receiver:ident $self:tt,
+            args: $args:tt, ret: $ret:tt, where: [Self: Sized $(,)?], default: []
+        }
+        $($items:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @forward_scan $forward $level []
+            {
+                attrs: $attrs, kind: fn, safety: $safety, name: $name, generics: $generics, receiver: $receiver $self,
+                args: $args, ret: $ret, where: [Self: Sized], default: []
+            }
+            $($items)*
+        }
+    };
+    (
+        @forward_scan $forward:tt $level:ident $unsized:tt
+        { attrs: $attrs:tt, kind: fn, safety: $safety:tt, name: $name:ident, generics: $generics:tt, receiver: value $($fn:tt)* }
+        $($items:tt)*
+    ) => {
+        $crate::macro_attr_impl! { @forward_scan $forward value [] $($items)* }
+    };
+    (
+        @forward_scan $forward:tt ref $unsized:tt
+        { attrs: $attrs:tt, kind: fn, safety: $safety:tt, name: $name:ident, generics: $generics:tt, receiver: mut $($fn:tt)* }
+        $($items:tt)*
+    ) => {
+        $crate::macro_attr_impl! { @forward_scan $forward mut $unsized $($items)* }
+    };
+    (
+        @forward_scan [[] $safety:tt $name:ident $items:tt $impl_params:tt $ty_params:tt $where:tt $trait:tt] $level:ident $unsized:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @forward_defaults [$safety $name $items $impl_params $ty_params $where $trait $unsized] $level
+        }
+    };
+    (
+        @forward_scan [[$($targets:tt)+] $safety:tt $name:ident $items:tt $impl_params:tt $ty_params:tt $where:tt $trait:tt] $level:ident $unsized:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @forward_targets [$safety $name $items $impl_params $ty_params $where $trait $unsized] [] $($targets)+
+        }
+    };
+    (
+        @forward_scan $forward:tt $level:ident $unsized:tt $item:tt $($items:tt)*
+    ) => {
+        $crate::macro_attr_impl! { @forward_scan $forward $level $unsized $($items)* }
+    };
+    (
+        @forward_defaults $forward:tt ref
+    ) => {
+        $crate::macro_attr_alloc! {
+            @defaults [$crate::macro_attr_impl] { @forward_impls $forward } [[ref] [mut]] [[Box] [Rc] [Arc]]
+        }
+    };
+    (
+        @forward_defaults $forward:tt mut
+    ) => {
+        $crate::macro_attr_alloc! {
+            @defaults [$crate::macro_attr_impl] { @forward_impls $forward } [[mut]] [[Box]]
+        }
+    };
+    (
+        @forward_defaults $forward:tt value
+    ) => {
+        $crate::macro_attr_alloc! {
+            @defaults [$crate::macro_attr_impl] { @forward_impls $forward } [] [[Box]]
+        }
+    };
+    (
+        @forward_targets $forward:tt [$($targets:tt)*]
+    ) => {
+        $crate::macro_attr_impl! { @forward_impls $forward [$($targets)*] }
+    };
+    (
+        @forward_targets $forward:tt [$($targets:tt)*] & mut $(, $($tail:tt)*)?
+    ) => {
+        $crate::macro_attr_impl! { @forward_targets $forward [$($targets)* [mut]] $($($tail)*)? }
+    };
+    (
+        @forward_targets $forward:tt [$($targets:tt)*] & $(, $($tail:tt)*)?
+    ) => {
+        $crate::macro_attr_impl! { @forward_targets $forward [$($targets)* [ref]] $($($tail)*)? }
+    };
+    (
+        @forward_targets $forward:tt [$($targets:tt)*] $target:ident $(, $($tail:tt)*)?
+    ) => {
+        $crate::macro_attr_impl! { @forward_targets $forward [$($targets)* [$target]] $($($tail)*)? }
+    };
+    (
+        @forward_targets $forward:tt $targets:tt $($tail:tt)+
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "invalid `Forward!` targets `", ::core::stringify!($($tail)+), "`; ",
+            "expected a list of `&`, `&mut`, `Box`, `Rc` and `Arc`"
+        ));
+    };
+    (
+        @forward_impls $forward:tt [$($target:tt)*]
+    ) => {
+        $(
+            $crate::macro_attr_impl! { @forward_impl $forward $target }
+        )*
+    };
+    (
+        @forward_impl $forward:tt [ref]
+    ) => {
+        $crate::macro_attr_impl! { @forward_emit $forward ref [&__T] }
+    };
+    (
+        @forward_impl $forward:tt [mut]
+    ) => {
+        $crate::macro_attr_impl! { @forward_emit $forward mut [&mut __T] }
+    };
+    (
+        @forward_impl $forward:tt [$target:ident]
+    ) => {
+        $crate::macro_attr_alloc! {
+            @path [$crate::macro_attr_impl] { @forward_alloc $forward $target } $target
+        }
+    };
+    (
+        @forward_alloc $forward:tt $target:ident [$($path:tt)+]
+    ) => {
+        $crate::macro_attr_impl! { @forward_emit $forward $target [$($path)+<__T>] }
+    };
+    (
+        @forward_emit
+        [[$($safety:tt)*] $name:ident [$($items:tt)*] [$($impl_params:tt)*] [$($ty_params:tt)*] [$($where:tt)*] $trait:tt [$($unsized:tt)*]]
+        $target:ident [$($self_ty:tt)+]
+    ) => {
+        $($safety)* impl<$($impl_params)* __T: $($unsized)* $name<$($ty_params)*>> $name<$($ty_params)*> for $($self_ty)+
+        where $($where)*
+        {
+            $(
+                $crate::macro_attr_impl! { @forward_item $target $trait $items }
+            )*
+        }
+    };
+    (
+        @forward_item $target:ident [$($trait:tt)*]
+        { attrs: [$($attrs:tt)*], kind: type, name: $name:ident, generics: [], bounds: $bounds:tt, where: [] }
+    ) => {
+        $crate::macro_attr_impl! {
+            @forward_cfg [] [$($attrs)*] [type $name = <__T as $($trait)*>::$name;]
+        }
+    };
+    (
+        @forward_item $target:ident $trait:tt
+        { attrs: $attrs:tt, kind: type, name: $name:ident, generics: [$($generics:tt)+], bounds: $bounds:tt, where: $where:tt }
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [[$crate::macro_attr_impl] { @forward_type [$trait $attrs $name $where] } []]
+            [] [] [] [] start $($generics)+
+        }
+    };
+    (
+        @forward_type [[$($trait:tt)*] [$($attrs:tt)*] $name:ident [$($where:tt)*]] [$($impl_params:tt)*] [$($ty_params:tt)*] $split_where:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @forward_cfg [] [$($attrs)*] [
+                type $name<$($impl_params)*> = <__T as $($trait)*>::$name<$($ty_params)*> where $($where)*;
+            ]
+        }
+    };
+    (
+        @forward_item $target:ident [$($trait:tt)*]
+        { attrs: [$($attrs:tt)*], kind: const, name: $name:ident, ty: [$ty:ty], default: $default:tt }
+    ) => {
+        $crate::macro_attr_impl! {
+            @forward_cfg [] [$($attrs)*] [const $name: $ty = <__T as $($trait)*>::$name;]
+        }
+    };
+    (
+        @forward_item $target:ident $trait:tt
+        {
+            attrs: $attrs:tt, kind: fn, safety: $safety:tt, name: $name:ident, generics: $generics:tt, receiver: $receiver:ident $self:tt,
+            args: $args:tt, ret: $ret:tt, where: [Self: Sized $(,)?], default: [{ $($default:tt)* }]
+        }
+    ) => {
+    };
+    (
+        @forward_item $target:ident $trait:tt
+        {
+            attrs: $attrs:tt, kind: fn, safety: $safety:tt, name: $name:ident, generics: $generics:tt, receiver: $receiver:ident [$($self:tt)*],
+            $($fn:tt)*
+        }
+    ) => {
+        $crate::macro_attr_impl! {
+            @forward_receiver $target $receiver [$trait $attrs $safety $name $generics [$($self)*] $($fn)*]
+        }
+    };
+    (
+        @forward_receiver $target:ident none $fn:tt
+    ) => {
+        $crate::macro_attr_impl! { @forward_fn [] [] $fn }
+    };
+    (
+        @forward_receiver $target:ident ref [$trait:tt $attrs:tt $safety:tt $name:ident $generics:tt [& $($lifetime:lifetime)? $self:ident] $($fn:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @forward_fn [&**$self,] [& $($lifetime)? $self,] [$trait $attrs $safety $name $generics [& $($lifetime)? $self] $($fn)*]
+        }
+    };
+    (
+        @forward_receiver mut mut [$trait:tt $attrs:tt $safety:tt $name:ident $generics:tt [& $($lifetime:lifetime)? mut $self:ident] $($fn:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @forward_fn [&mut **$self,] [& $($lifetime)? mut $self,] [$trait $attrs $safety $name $generics [& $($lifetime)? mut $self] $($fn)*]
+        }
+    };
+    (
+        @forward_receiver Box mut [$trait:tt $attrs:tt $safety:tt $name:ident $generics:tt [& $($lifetime:lifetime)? mut $self:ident] $($fn:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @forward_fn [&mut **$self,] [& $($lifetime)? mut $self,] [$trait $attrs $safety $name $generics [& $($lifetime)? mut $self] $($fn)*]
+        }
+    };
+    (
+        @forward_receiver Box value [$trait:tt $attrs:tt $safety:tt $name:ident $generics:tt [$self:ident] $($fn:tt)*]
+    ) => {
+        $crate::macro_attr_impl! { @forward_fn [*$self,] [$self,] [$trait $attrs $safety $name $generics [$self] $($fn)*] }
+    };
+    (
+        @forward_receiver Box value [$trait:tt $attrs:tt $safety:tt $name:ident $generics:tt [mut $self:ident] $($fn:tt)*]
+    ) => {
+        $crate::macro_attr_impl! { @forward_fn [*$self,] [$self,] [$trait $attrs $safety $name $generics [$self] $($fn)*] }
+    };
+    (
+        @forward_receiver ref $receiver:ident [$trait:tt $attrs:tt $safety:tt $name:ident $generics:tt [$($self:tt)*] $($fn:tt)*]
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "`Forward!` cannot forward `", ::core::stringify!($name), "` with receiver `", ::core::stringify!($($self)*), "` ",
+            "to `&T`"
+        ));
+    };
+    (
+        @forward_receiver mut $receiver:ident [$trait:tt $attrs:tt $safety:tt $name:ident $generics:tt [$($self:tt)*] $($fn:tt)*]
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "`Forward!` cannot forward `", ::core::stringify!($name), "` with receiver `", ::core::stringify!($($self)*), "` ",
+            "to `&mut T`"
+        ));
+    };
+    (
+        @forward_receiver $target:ident $receiver:ident [$trait:tt $attrs:tt $safety:tt $name:ident $generics:tt [$($self:tt)*] $($fn:tt)*]
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "`Forward!` cannot forward `", ::core::stringify!($name), "` with receiver `", ::core::stringify!($($self)*), "` ",
+            "to `", ::core::stringify!($target), "<T>`"
+        ));
+    };
+    (
+        @forward_fn $call:tt $params:tt [$trait:tt $attrs:tt $safety:tt $name:ident [] $($fn:tt)*]
+    ) => {
+        $crate::macro_attr_impl! { @forward_fn_emit [] $call $params [$trait $attrs $safety $name [] $($fn)*] }
+    };
+    (
+        @forward_fn $call:tt $params:tt [$trait:tt $attrs:tt $safety:tt $name:ident [$($generics:tt)+] $($fn:tt)*]
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_generics [[$crate::macro_attr_impl] { @forward_turbofish [$call $params [$trait $attrs $safety $name [$($generics)+] $($fn)*]] } []]
+            [] [] [] [] start $($generics)+
+        }
+    };
+    (
+        @forward_turbofish $fn:tt $impl_params:tt [$($ty_params:tt)*] $where:tt
+    ) => {
+        $crate::macro_attr_impl! { @forward_turbofish_args $fn [] $($ty_params)* }
+    };
+    (
+        @forward_turbofish_args [$call:tt $params:tt $fn:tt] [$($args:tt)*]
+    ) => {
+        $crate::macro_attr_impl! { @forward_fn_emit [$($args)*] $call $params $fn }
+    };
+    (
+        @forward_turbofish_args $fn:tt $args:tt $lifetime:lifetime, $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! { @forward_turbofish_args $fn $args $($tail)* }
+    };
+    (
+        @forward_turbofish_args $fn:tt [$($args:tt)*] $param:ident, $($tail:tt)*
+    ) => {
+        $crate::macro_attr_impl! { @forward_turbofish_args $fn [$($args)* $param,] $($tail)* }
+    };
+    (
+        @forward_fn_emit $turbofish:tt [$($call:tt)*] [$($params:tt)*]
+        [
+            $trait:tt $attrs:tt $safety:tt $name:ident $generics:tt $self:tt
+            args: [{ pat: $pat:tt, ty: [$arg_ty:ty] } $($args:tt)*],
+            $($fn:tt)*
+        ]
+    ) => {
+        $crate::macro_attr_impl! {
+            @forward_fn_emit $turbofish [$($call)* __arg,] [$($params)* __arg: $arg_ty,]
+            [$trait $attrs $safety $name $generics $self args: [$($args)*], $($fn)*]
+        }
+    };
+    (
+        @forward_fn_emit [$($turbofish:tt)*] [$($call:tt)*] [$($params:tt)*]
+        [
+            [$($trait:tt)*] [$($attrs:tt)*] [$($safety:tt)*] $name:ident [$($generics:tt)*] $self:tt
+            args: [],
+            ret: [$($ret:ty)?],
+            where: [$($where:tt)*],
+            default: $default:tt
+        ]
+    ) => {
+        $crate::macro_attr_impl! {
+            @forward_cfg [] [$($attrs)*] [
+                $($safety)* fn $name<$($generics)*>($($params)*) $(-> $ret)? where $($where)* {
+                    $($safety)* { <__T as $($trait)*>::$name::<$($turbofish)*>($($call)*) }
+                }
+            ]
+        }
+    };
+    (
+        @forward_cfg [$($cfgs:tt)*] [#[cfg $($cfg:tt)*] $($attrs:tt)*] $item:tt
+    ) => {
+        $crate::macro_attr_impl! { @forward_cfg [$($cfgs)* #[cfg $($cfg)*]] [$($attrs)*] $item }
+    };
+    (
+        @forward_cfg $cfgs:tt [#[$($attr:tt)*] $($attrs:tt)*] $item:tt
+    ) => {
+        $crate::macro_attr_impl! { @forward_cfg $cfgs [$($attrs)*] $item }
+    };
+    (
+        @forward_cfg [$($cfgs:tt)*] [] [$($item:tt)*]
+    ) => {
+        $($cfgs)*
+        $($item)*
+    };
+    (@as_item $($i:item)*) => {$($i)*};
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! macro_attr_alloc {
+    (
+        @defaults [$($callback:tt)+] { $($callback_args:tt)* } [$($targets:tt)*] [$($alloc_targets:tt)*]
+    ) => {
+        $($callback)+! { $($callback_args)* [$($targets)* $($alloc_targets)*] }
+    };
+    (
+        @path [$($callback:tt)+] { $($callback_args:tt)* } Box
+    ) => {
+        $($callback)+! { $($callback_args)* [$crate::alloc::boxed::Box] }
+    };
+    (
+        @path [$($callback:tt)+] { $($callback_args:tt)* } Rc
+    ) => {
+        $($callback)+! { $($callback_args)* [$crate::alloc::rc::Rc] }
+    };
+    (
+        @path [$($callback:tt)+] { $($callback_args:tt)* } Arc
+    ) => {
+        $($callback)+! { $($callback_args)* [$crate::alloc::sync::Arc] }
+    };
+    (
+        @path $callback:tt $callback_args:tt $target:ident
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown `Forward!` target `", ::core::stringify!($target), "`; ",
+            "expected `&`, `&mut`, `Box`, `Rc` or `Arc`"
+        ));
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! macro_attr_alloc {
+    (
+        @defaults [$($callback:tt)+] { $($callback_args:tt)* } [$($targets:tt)*] [$($alloc_targets:tt)*]
+    ) => {
+        $($callback)+! { $($callback_args)* [$($targets)*] }
+    };
+    (
+        @path $callback:tt $callback_args:tt Box
+    ) => {
+        ::core::compile_error!("`Forward!` target `Box` requires the `alloc` feature of `macro-attr-2018`");
+    };
+    (
+        @path $callback:tt $callback_args:tt Rc
+    ) => {
+        ::core::compile_error!("`Forward!` target `Rc` requires the `alloc` feature of `macro-attr-2018`");
+    };
+    (
+        @path $callback:tt $callback_args:tt Arc
+    ) => {
+        ::core::compile_error!("`Forward!` target `Arc` requires the `alloc` feature of `macro-attr-2018`");
+    };
+    (
+        @path $callback:tt $callback_args:tt $target:ident
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown `Forward!` target `", ::core::stringify!($target), "`; ",
+            "expected `&`, `&mut`, `Box`, `Rc` or `Arc`"
+        ));
     };
-    (@as_item $($i:item)*) => {$($i)*};
 }
@@ -0,0 +1,47 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::macro_attr;
+
+macro_rules! Names {
+    (($($names:ident),* $(,)?) $vis:vis struct $name:ident $($tail:tt)*) => {
+        impl $name {
+            const NAMES: &'static [&'static str] = &[$(stringify!($names)),*];
+        }
+    };
+}
+
+macro_rules! Impl {
+    (($($body:tt)*) $vis:vis struct $name:ident $($tail:tt)*) => {
+        impl $name {
+            $($body)*
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(Clone, Names![a, b], Impl!{ fn answer() -> u8 { 42 } }, Debug)]
+    struct Brackets;
+}
+
+macro_attr! {
+    #[derive(Names!(c))]
+    #[cfg_attr(all(), derive(Impl! { fn answer() -> u8 { 7 } }))]
+    struct Parens;
+}
+
+#[test]
+fn test_arg_delimiters() {
+    assert_eq!(Brackets::NAMES, &["a", "b"]);
+    assert_eq!(Brackets::answer(), 42);
+    assert_eq!(format!("{:?}", Brackets.clone()), "Brackets");
+    assert_eq!(Parens::NAMES, &["c"]);
+    assert_eq!(Parens::answer(), 7);
+}
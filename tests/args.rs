@@ -0,0 +1,92 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::{macro_attr, macro_attr_args};
+
+trait Describe {
+    const NAME: &'static str;
+    const TAGS: &'static [&'static str];
+    const VERBOSE: bool;
+    const LIMIT: u32;
+}
+
+mod parsers {
+    use macro_attr_2018::macro_attr_args;
+
+    macro_attr_args! {
+        /// Arguments of `Describe!`.
+        pub(crate) DescribeArgs {
+            name = [""],
+            tags = [],
+            verbose = [false],
+            limit = [10],
+        }
+    }
+}
+
+use parsers::DescribeArgs;
+
+#[macro_export]
+macro_rules! Describe {
+    (($($args:tt)*) $vis:vis struct $name:ident $($tail:tt)*) => {
+        DescribeArgs! { $crate::Describe! { @impl $name } ($($args)*) }
+    };
+    (
+        @impl $name:ident
+        { name: [$display:expr], tags: [$($tag:expr),*], verbose: [$verbose:expr], limit: [$limit:expr] }
+    ) => {
+        impl Describe for $name {
+            const NAME: &'static str = $display;
+            const TAGS: &'static [&'static str] = &[$($tag),*];
+            const VERBOSE: bool = $verbose;
+            const LIMIT: u32 = $limit;
+        }
+    };
+}
+
+macro_attr_args! {
+    Empty { }
+    Pair { a = [1], b = [2] }
+}
+
+macro_rules! Nothing {
+    ($value:literal {}) => { $value };
+}
+
+macro_rules! Sizes {
+    ({ a: [$a:expr], b: [$b:ty] }) => { ($a, ::core::mem::size_of::<$b>()) };
+}
+
+macro_attr! {
+    #[derive(Describe!)]
+    struct Defaults;
+}
+
+macro_attr! {
+    #[derive(Describe!(limit = 2 + 3, verbose, tags("a", "b"), name = "named", limit = 7,))]
+    struct Given;
+}
+
+macro_attr! {
+    #[derive(Describe![tags("c"), verbose = false])]
+    struct Brackets;
+}
+
+#[test]
+fn test_args() {
+    assert_eq!((Defaults::NAME, Defaults::TAGS, Defaults::VERBOSE, Defaults::LIMIT), ("", &[][..], false, 10));
+    assert_eq!((Given::NAME, Given::TAGS, Given::VERBOSE, Given::LIMIT), ("named", &["a", "b"][..], true, 7));
+    assert_eq!((Brackets::TAGS, Brackets::VERBOSE), (&["c"][..], false));
+    assert_eq!(Empty! { Nothing! { 1 } () }, 1);
+    assert_eq!(Pair! { Sizes! {} (b = [u8; 3]) }, (1, 3));
+    assert_eq!(Pair! { Sizes! {} (b = ::std::collections::HashMap<u8, Vec<(u8, u16)>>, a = 1 << 2) }, (4, 48));
+    assert_eq!(Pair! { Sizes! {} (a = i64::max(1, 2), b = Result<Vec<Vec<u8>>, Option<u8>>) }, (2, 24));
+    assert_eq!(Pair! { Sizes! {} (a = 16 >> 2, b = <Vec<(u8, u16)> as IntoIterator>::Item) }, (4, 4));
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::macro_attr;
+
+macro_rules! FieldNames {
+    (() $vis:vis struct $name:ident { $($field:ident : $ty:ty),* $(,)? }) => {
+        impl $name {
+            const FIELDS: &'static [&'static str] = &[$(stringify!($field)),*];
+        }
+    };
+}
+
+macro_rules! AddField {
+    (
+        @chain $macro_attr:path { $($state:tt)* }
+        ($new_field:ident : $new_ty:ty)
+        $(#[$($attrs:tt)*])*
+        $vis:vis struct $name:ident { $($field:ident : $ty:ty),* $(,)? }
+    ) => {
+        $macro_attr! {
+            $($state)*
+            $(#[$($attrs)*])*
+            $vis struct $name { $($field: $ty,)* $new_field: $new_ty }
+        }
+    };
+}
+
+macro_rules! Attrs {
+    (
+        @chain $macro_attr:path { $($state:tt)* }
+        ($attrs_name:ident)
+        $(#[$($attrs:tt)*])*
+        $vis:vis struct $name:ident $($tail:tt)+
+    ) => {
+        const $attrs_name: &[&str] = &[$(stringify!($($attrs)*)),*];
+
+        $macro_attr! {
+            $($state)*
+            $(#[$($attrs)*])*
+            $vis struct $name $($tail)+
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(Debug, Default, FieldNames!)]
+    #[@chain AddField!(b: u16)]
+    #[@chain AddField!(c: u32)]
+    struct Chained { a: u8 }
+}
+
+macro_attr! {
+    #[derive(Debug, FieldNames!)]
+    #[@chain AddField!(b: u16)]
+    struct Single { a: u8 }
+}
+
+macro_rules! Plain {
+    (($attrs_name:ident) $(#[$attr:meta])* $vis:vis struct $name:ident $($tail:tt)+) => {
+        const $attrs_name: &[&str] = &[$(stringify!($attr)),*];
+
+        $(#[$attr])*
+        $vis struct $name $($tail)+
+    };
+}
+
+macro_attr! {
+    #[derive(Debug, FieldNames!)]
+    #[@chain AddField!(b: u16)]
+    #[Plain!(MIXED)]
+    struct Mixed { a: u8 }
+}
+
+macro_attr! {
+    #[@chain Attrs!(FIRST)]
+    #[derive(Clone, Copy, FieldNames!)]
+    #[@chain AddField!(y: i32)]
+    #[@chain Attrs!(SECOND)]
+    #[repr(C)]
+    struct Point { x: i32 }
+}
+
+fn squash(s: &str) -> String {
+    s.split_whitespace().collect()
+}
+
+#[test]
+fn test_attr_chain() {
+    assert_eq!(Chained::FIELDS, ["a", "b", "c"]);
+    let chained = Chained::default();
+    assert_eq!(format!("{:?}", chained), "Chained { a: 0, b: 0, c: 0 }");
+    assert_eq!(Single::FIELDS, ["a", "b"]);
+    assert_eq!(format!("{:?}", Single { a: 1, b: 2 }), "Single { a: 1, b: 2 }");
+    assert_eq!(Mixed::FIELDS, ["a", "b"]);
+    let mixed = Mixed { a: 1, b: 2 };
+    assert_eq!((mixed.a, mixed.b), (1, 2));
+    assert_eq!(format!("{:?}", mixed), "Mixed { a: 1, b: 2 }");
+    assert_eq!(MIXED.iter().copied().map(squash).collect::<Vec<_>>(), ["derive(Debug)"]);
+    let point = Point { x: 1, y: 2 };
+    let copy = point;
+    assert_eq!((point.x, copy.y), (1, 2));
+    assert_eq!(Point::FIELDS, ["x", "y"]);
+    assert_eq!(FIRST.iter().copied().map(squash).collect::<Vec<_>>(), [
+        "derive(Clone,Copy,FieldNames!)",
+        "@chainAddField!(y:i32)",
+        "@chainAttrs!(SECOND)",
+        "repr(C)",
+    ]);
+    assert_eq!(SECOND.iter().copied().map(squash).collect::<Vec<_>>(), [
+        "derive(Clone,Copy,FieldNames!)",
+        "repr(C)",
+    ]);
+}
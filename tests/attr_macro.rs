@@ -0,0 +1,86 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::macro_attr;
+
+trait TypeName {
+    fn type_name() -> &'static str;
+}
+
+macro_rules! TypeName {
+    (() $vis:vis $kw:ident $name:ident $($tail:tt)+) => {
+        impl TypeName for $name {
+            fn type_name() -> &'static str { stringify!($name) }
+        }
+    };
+}
+
+macro_rules! Logged {
+    (
+        ($prefix:literal)
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident ($($arg:ident : $arg_ty:ty),* $(,)?) -> $ret:ty $body:block
+    ) => {
+        $(#[$attr])*
+        $vis fn $name($($arg: $arg_ty),*) -> ($ret, String) {
+            let log = format!(concat!($prefix, stringify!($name), "({:?})"), ($($arg,)*));
+            ($body, log)
+        }
+    };
+}
+
+macro_attr! {
+    #[Logged!("call: ")]
+    #[inline]
+    fn add(a: u32, b: u32) -> u32 { a + b }
+}
+
+macro_rules! Newtype {
+    (
+        ($inner:ty)
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident;
+    ) => {
+        $(#[$attr])*
+        $vis struct $name(pub $inner);
+    };
+}
+
+macro_attr! {
+    #[derive(Debug, TypeName!, PartialEq)]
+    #[Newtype!(u8)]
+    pub struct Byte;
+}
+
+macro_rules! Unit {
+    (() $(#[$attr:meta])* $vis:vis struct $name:ident $($tail:tt)+) => {
+        $(#[$attr])*
+        $vis struct $name;
+    };
+}
+
+macro_attr! {
+    #[Unit!]
+    #[derive(Clone, Copy)]
+    struct Erased { _x: u32 }
+}
+
+#[test]
+fn test_attr_macro() {
+    assert_eq!(add(1, 2), (3, "call: add((1, 2))".to_string()));
+}
+
+#[test]
+fn test_attr_macro_with_derives() {
+    assert_eq!(Byte(7), Byte(7));
+    assert_eq!(Byte::type_name(), "Byte");
+    let e = Erased;
+    let _ = (e, e);
+}
@@ -0,0 +1,206 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::{macro_attr, macro_attr_bundle};
+
+trait TypeName {
+    fn type_name() -> &'static str;
+}
+
+macro_rules! TypeName {
+    (() $vis:vis $kw:ident $name:ident $($tail:tt)+) => {
+        impl TypeName for $name {
+            fn type_name() -> &'static str { stringify!($name) }
+        }
+    };
+}
+
+macro_rules! Attrs {
+    (() $(#[$($attr:tt)*])* $vis:vis struct $name:ident;) => {
+        $(#[$($attr)*])*
+        $vis struct $name;
+
+        impl $name {
+            const ATTRS: &'static [&'static str] = &[$(stringify!($($attr)*)),*];
+        }
+    };
+}
+
+macro_attr_bundle! {
+    Hashed = PartialEq, Eq, Hash;
+}
+
+macro_attr! {
+    /// Documented.
+    #[derive(Clone, Copy, TypeName!, Debug)]
+    #[repr(C)]
+    #[cfg_attr(test, derive(Default), allow(dead_code))]
+    #[derive(PartialOrd if test, @bundle Hashed!, Ord)]
+    #[Attrs!]
+    struct Ordered;
+}
+
+macro_attr! {
+    /// Line 0.
+    /// Line 1.
+    /// Line 2.
+    /// Line 3.
+    /// Line 4.
+    /// Line 5.
+    /// Line 6.
+    /// Line 7.
+    /// Line 8.
+    /// Line 9.
+    /// Line 10.
+    /// Line 11.
+    /// Line 12.
+    /// Line 13.
+    /// Line 14.
+    /// Line 15.
+    /// Line 16.
+    /// Line 17.
+    /// Line 18.
+    /// Line 19.
+    /// Line 20.
+    /// Line 21.
+    /// Line 22.
+    /// Line 23.
+    /// Line 24.
+    /// Line 25.
+    /// Line 26.
+    /// Line 27.
+    /// Line 28.
+    /// Line 29.
+    /// Line 30.
+    /// Line 31.
+    /// Line 32.
+    /// Line 33.
+    /// Line 34.
+    /// Line 35.
+    /// Line 36.
+    /// Line 37.
+    /// Line 38.
+    /// Line 39.
+    /// Line 40.
+    /// Line 41.
+    /// Line 42.
+    /// Line 43.
+    /// Line 44.
+    /// Line 45.
+    /// Line 46.
+    /// Line 47.
+    /// Line 48.
+    /// Line 49.
+    /// Line 50.
+    /// Line 51.
+    /// Line 52.
+    /// Line 53.
+    /// Line 54.
+    /// Line 55.
+    /// Line 56.
+    /// Line 57.
+    /// Line 58.
+    /// Line 59.
+    /// Line 60.
+    /// Line 61.
+    /// Line 62.
+    /// Line 63.
+    /// Line 64.
+    /// Line 65.
+    /// Line 66.
+    /// Line 67.
+    /// Line 68.
+    /// Line 69.
+    /// Line 70.
+    /// Line 71.
+    /// Line 72.
+    /// Line 73.
+    /// Line 74.
+    /// Line 75.
+    /// Line 76.
+    /// Line 77.
+    /// Line 78.
+    /// Line 79.
+    /// Line 80.
+    /// Line 81.
+    /// Line 82.
+    /// Line 83.
+    /// Line 84.
+    /// Line 85.
+    /// Line 86.
+    /// Line 87.
+    /// Line 88.
+    /// Line 89.
+    /// Line 90.
+    /// Line 91.
+    /// Line 92.
+    /// Line 93.
+    /// Line 94.
+    /// Line 95.
+    /// Line 96.
+    /// Line 97.
+    /// Line 98.
+    /// Line 99.
+    #[derive(Clone, TypeName!)]
+    /// Line 100.
+    /// Line 101.
+    /// Line 102.
+    /// Line 103.
+    /// Line 104.
+    /// Line 105.
+    /// Line 106.
+    /// Line 107.
+    /// Line 108.
+    /// Line 109.
+    /// Line 110.
+    /// Line 111.
+    /// Line 112.
+    /// Line 113.
+    /// Line 114.
+    /// Line 115.
+    /// Line 116.
+    /// Line 117.
+    /// Line 118.
+    /// Line 119.
+    #[Attrs!]
+    struct LongDoc;
+}
+
+fn squash(attrs: &[&str]) -> Vec<String> {
+    attrs.iter().map(|x| x.split_whitespace().collect()).collect()
+}
+
+#[test]
+fn test_attr_order() {
+    assert_eq!(squash(Ordered::ATTRS), [
+        "doc=r\"Documented.\"",
+        "derive(Clone,Copy,Debug)",
+        "repr(C)",
+        "cfg_attr(test,derive(Default))",
+        "cfg_attr(test,allow(dead_code))",
+        "cfg_attr(all(test),derive(PartialOrd))",
+        "derive(PartialEq,Eq,Hash)",
+        "derive(Ord)",
+    ]);
+    assert_eq!(Ordered::type_name(), "Ordered");
+    let ordered: Ordered = Default::default();
+    assert_eq!(ordered.partial_cmp(&Ordered), Some(std::cmp::Ordering::Equal));
+}
+
+#[test]
+fn test_long_doc() {
+    let attrs = squash(LongDoc::ATTRS);
+    assert_eq!(attrs.len(), 121);
+    for (i, attr) in attrs[..100].iter().chain(&attrs[101..]).enumerate() {
+        assert_eq!(*attr, format!("doc=r\"Line{}.\"", i));
+    }
+    assert_eq!(attrs[100], "derive(Clone)");
+    assert_eq!(LongDoc::type_name(), "LongDoc");
+}
@@ -0,0 +1,77 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::{macro_attr, macro_attr_bundle};
+
+trait TypeName {
+    fn type_name() -> &'static str;
+}
+
+macro_rules! TypeName {
+    (($($prefix:literal)?) $vis:vis $kw:ident $name:ident $($tail:tt)+) => {
+        impl TypeName for $name {
+            fn type_name() -> &'static str { concat!($($prefix,)? stringify!($name)) }
+        }
+    };
+}
+
+macro_rules! Siblings {
+    (@derives [$($std:path),*] [$($macros:ident !),*] () $vis:vis $kw:ident $name:ident $($tail:tt)+) => {
+        impl $name {
+            const SIBLINGS: &'static [&'static str] = &[$(stringify!($std),)* $(stringify!($macros),)*];
+        }
+    };
+}
+
+macro_attr_bundle! {
+    /// Total order.
+    Ordinal = PartialEq, Eq, PartialOrd, Ord;
+    IdEnum = Debug, Clone, Copy, @bundle Ordinal!, Hash, TypeName!("id ");
+}
+
+mod bundles {
+    use macro_attr_2018::macro_attr_bundle;
+
+    macro_attr_bundle! {
+        pub(crate) Described = Debug, @derives Siblings!,
+    }
+}
+
+macro_attr! {
+    #[derive(@bundle IdEnum!, Default)]
+    enum Color {
+        #[default]
+        Red,
+        Green,
+    }
+}
+
+macro_attr! {
+    #[derive(@bundle bundles::Described!, Clone, TypeName!)]
+    struct Point { x: i32 }
+}
+
+macro_attr! {
+    #[cfg_attr(all(), derive(@bundle Ordinal!))]
+    #[cfg_attr(any(), derive(@bundle IdEnum!))]
+    struct Conditional(u8);
+}
+
+#[test]
+fn test_bundles() {
+    assert!(Color::Red < Color::Green);
+    assert_eq!(Color::default(), Color::Red);
+    assert_eq!(format!("{:?}", Color::Green.clone()), "Green");
+    assert_eq!(Color::type_name(), "id Color");
+    assert_eq!(Point::type_name(), "Point");
+    assert_eq!(Point::SIBLINGS, &["Debug", "Clone", "Siblings", "TypeName"]);
+    assert_eq!(format!("{:?}", Point { x: 1 }), "Point { x: 1 }");
+    assert!(Conditional(1) < Conditional(2));
+}
@@ -0,0 +1,52 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::macro_attr;
+
+trait TypeName {
+    fn type_name() -> &'static str;
+}
+
+macro_rules! TypeName {
+    (() $vis:vis $kw:ident $name:ident $($tail:tt)+) => {
+        impl TypeName for $name {
+            fn type_name() -> &'static str { stringify!($name) }
+        }
+    };
+}
+
+macro_attr! {
+    #[cfg_attr(test, derive(TypeName!, Debug))]
+    #[cfg_attr(not(test), derive(Missing!, NotATrait))]
+    struct Enabled;
+}
+
+macro_attr! {
+    #[derive(Clone)]
+    #[cfg_attr(test, repr(u8), derive(Copy, TypeName!), allow(dead_code))]
+    enum Several { A, B }
+}
+
+macro_attr! {
+    #[cfg_attr(test, cfg_attr(not(test), derive(Missing!)), derive(PartialEq, TypeName!))]
+    #[cfg_attr(test,)]
+    struct Nested;
+}
+
+#[test]
+fn test_cfg_attr() {
+    assert_eq!(Enabled::type_name(), "Enabled");
+    assert_eq!(format!("{:?}", Enabled), "Enabled");
+    assert_eq!(Several::type_name(), "Several");
+    let a = Several::B;
+    assert_eq!((a, a).0 as u8, 1);
+    assert_eq!(Nested::type_name(), "Nested");
+    assert!(Nested == Nested);
+}
@@ -0,0 +1,79 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::macro_attr;
+
+macro_rules! TryFromRepr {
+    (@attrs [$(#[$($attr:tt)*])*] () $vis:vis enum $name:ident { $($body:tt)* }) => {
+        TryFromRepr! {
+            @find_repr ($name, { $($body)* }),
+            $([$($attr)*])*
+        }
+    };
+
+    (@find_repr $fixed:tt, [repr($prim:ty)] $($tail:tt)*) => {
+        TryFromRepr! {
+            @collect_variants $fixed, $prim,
+        }
+    };
+
+    (@find_repr $fixed:tt, [$($attr:tt)*] $($tail:tt)*) => {
+        TryFromRepr! {
+            @find_repr $fixed, $($tail)*
+        }
+    };
+
+    (@collect_variants ($name:ident, { $($var:ident),* $(,)? }), $prim:ty,) => {
+        impl TryFrom<$prim> for $name {
+            type Error = $prim;
+            fn try_from(src: $prim) -> Result<$name, $prim> {
+                $(
+                    if src == $name::$var as $prim {
+                        return Ok($name::$var);
+                    }
+                )*
+                Err(src)
+            }
+        }
+    };
+}
+
+macro_rules! Doc {
+    (@attrs [$(#[$attr:meta])*] () $vis:vis $kw:ident $name:ident $($tail:tt)+) => {
+        impl $name {
+            pub fn attrs() -> &'static [&'static str] {
+                &[$(stringify!($attr)),*]
+            }
+        }
+    };
+}
+
+macro_attr! {
+    /// Doc comment.
+    #[derive(Debug, PartialEq, @attrs TryFromRepr!)]
+    #[repr(u16)]
+    enum Get { Up, Down, AllAround }
+}
+
+macro_attr! {
+    #[derive(Clone)]
+    #[allow(dead_code)]
+    #[derive(@attrs Doc!, Copy)]
+    #[must_use]
+    struct Attrs;
+}
+
+#[test]
+fn test_derive_attrs() {
+    assert_eq!(Get::try_from(0u16), Ok(Get::Up));
+    assert_eq!(Get::try_from(2u16), Ok(Get::AllAround));
+    assert_eq!(Get::try_from(3u16), Err(3u16));
+    assert_eq!(Attrs::attrs(), &["allow(dead_code)", "must_use"]);
+}
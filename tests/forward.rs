@@ -0,0 +1,144 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::{macro_attr, Forward};
+
+macro_attr! {
+    #[derive(Forward!)]
+    trait Shape<'a, A: Clone = u8> where A: 'a {
+        type Unit;
+        const SIDES: u32;
+        fn area(&self) -> f64;
+        fn label(&self, prefix: &'a A) -> A { prefix.clone() }
+        /// # Safety
+        ///
+        /// Nothing to uphold.
+        unsafe fn raw(&self) -> u8;
+        fn unit() -> u8;
+        fn double(&self) -> f64 where Self: Sized { self.area() * 2.0 }
+    }
+}
+
+macro_attr! {
+    #[derive(Forward!)]
+    trait Generic {
+        fn count<T: Clone>(&self, items: T) -> usize where Vec<u8>: From<T> { Vec::from(items).len() }
+        fn pick<'a, T: Default, const N: usize>(&self, at: &'a u8) -> ([T; N], &'a u8) { ([(); N].map(|()| T::default()), at) }
+        fn size(&self) -> usize where Self: Sized;
+    }
+}
+
+macro_attr! {
+    #[derive(@parsed Forward!)]
+    trait Counter {
+        fn increment(&mut self, by: u32);
+        fn get(&self) -> u32;
+    }
+}
+
+macro_attr! {
+    #[derive(Forward!)]
+    trait Consume {
+        fn consume(self) -> u32;
+    }
+}
+
+macro_attr! {
+    #[derive(Forward!(&))]
+    trait Named {
+        fn name(&self) -> &'static str;
+    }
+}
+
+macro_attr! {
+    #[derive(Forward!)]
+    trait Patterns {
+        type Item<'a>: Clone where Self: 'a;
+        fn item(&self) -> Self::Item<'_>;
+        fn sum(&self, mut x: u8, _: u8, (a, b): (u8, u8)) -> u8 { x += a + b; x }
+    }
+}
+
+struct Square(f64);
+
+impl<'a> Shape<'a, u8> for Square {
+    type Unit = f64;
+    const SIDES: u32 = 4;
+    fn area(&self) -> f64 { self.0 * self.0 }
+    fn label(&self, prefix: &'a u8) -> u8 { prefix + 1 }
+    unsafe fn raw(&self) -> u8 { 7 }
+    fn unit() -> u8 { 1 }
+}
+
+impl Generic for Square {
+    fn size(&self) -> usize { 8 }
+}
+
+impl Patterns for Square {
+    type Item<'a> = &'a f64;
+    fn item(&self) -> &f64 { &self.0 }
+}
+
+#[derive(Default)]
+struct Clicks(u32);
+
+impl Counter for Clicks {
+    fn increment(&mut self, by: u32) { self.0 += by; }
+    fn get(&self) -> u32 { self.0 }
+}
+
+impl Consume for Clicks {
+    fn consume(self) -> u32 { self.0 }
+}
+
+impl Named for Clicks {
+    fn name(&self) -> &'static str { "clicks" }
+}
+
+#[test]
+fn test_forward_refs() {
+    let square = Square(3.0);
+    let by_ref = &square;
+    assert_eq!(<&Square as Shape>::area(&by_ref), 9.0);
+    assert_eq!(<&mut Square as Shape>::area(&&mut Square(2.0)), 4.0);
+    assert_eq!(<&Square as Shape>::label(&by_ref, &1), 2);
+    assert_eq!(<&Square as Shape>::double(&by_ref), 18.0);
+    assert_eq!(unsafe { <&Square as Shape>::raw(&by_ref) }, 7);
+    assert_eq!(<&Square as Shape>::unit(), 1);
+    assert_eq!(<&Square as Shape>::SIDES, 4);
+    let _: <&Square as Shape>::Unit = 1.0;
+    assert_eq!(<&Square as Generic>::count(&by_ref, [1, 2, 3]), 3);
+    assert_eq!(<&Square as Generic>::pick::<u8, 2>(&by_ref, &0), ([0, 0], &0));
+    assert_eq!(<&Square as Generic>::size(&by_ref), 8);
+    assert_eq!(<&Square as Patterns>::item(&by_ref), &3.0);
+    assert_eq!(<&Square as Patterns>::sum(&by_ref, 1, 2, (3, 4)), 8);
+    let mut clicks = Clicks::default();
+    let mut by_mut = &mut clicks;
+    <&mut Clicks as Counter>::increment(&mut by_mut, 2);
+    assert_eq!(<&mut Clicks as Counter>::get(&by_mut), 2);
+    assert_eq!(<&Clicks as Named>::name(&&clicks), "clicks");
+    assert_eq!(clicks.consume(), 2);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_forward_alloc() {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    assert_eq!(<Box<Square> as Shape>::area(&Box::new(Square(2.0))), 4.0);
+    assert_eq!(<Rc<Square> as Shape>::area(&Rc::new(Square(2.0))), 4.0);
+    assert_eq!(<Arc<Square> as Shape>::area(&Arc::new(Square(2.0))), 4.0);
+    assert_eq!(<Box<Square> as Patterns>::item(&Box::new(Square(2.0))), &2.0);
+    let mut boxed = Box::new(Clicks(1));
+    <Box<Clicks> as Counter>::increment(&mut boxed, 1);
+    assert_eq!(<Box<Clicks> as Counter>::get(&boxed), 2);
+    assert_eq!(<Box<Clicks> as Consume>::consume(boxed), 2);
+}
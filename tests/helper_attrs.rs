@@ -0,0 +1,121 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::macro_attr;
+
+macro_rules! FieldNames {
+    (() $vis:vis struct $name:ident { $($body:tt)* }) => {
+        FieldNames! {
+            @collect $name [] $($body)*
+        }
+    };
+
+    (@collect $name:ident [$($names:tt)*] #[macro_attr(skip)] $field:ident : $ty:ty $(, $($tail:tt)*)?) => {
+        FieldNames! {
+            @collect $name [$($names)*] $($($tail)*)?
+        }
+    };
+
+    (@collect $name:ident [$($names:tt)*] #[FieldNames!(rename = $new:literal)] $field:ident : $ty:ty $(, $($tail:tt)*)?) => {
+        FieldNames! {
+            @collect $name [$($names)* $new,] $($($tail)*)?
+        }
+    };
+
+    (@collect $name:ident [$($names:tt)*] #[$attr:meta] $($tail:tt)*) => {
+        FieldNames! {
+            @collect $name [$($names)*] $($tail)*
+        }
+    };
+
+    (@collect $name:ident [$($names:tt)*] $field:ident : $ty:ty $(, $($tail:tt)*)?) => {
+        FieldNames! {
+            @collect $name [$($names)* stringify!($field),] $($($tail)*)?
+        }
+    };
+
+    (@collect $name:ident [$($names:tt)*]) => {
+        impl $name {
+            pub fn field_names() -> &'static [&'static str] {
+                &[$($names)*]
+            }
+        }
+    };
+}
+
+macro_rules! Dummy {
+    ($($tts:tt)*) => {};
+}
+
+macro_attr! {
+    #[derive(Debug, Default, FieldNames!)]
+    struct Named {
+        #[macro_attr(skip)]
+        hidden: u8,
+        /// Documented.
+        #[FieldNames!(rename = "Visible")]
+        visible: Vec<(u8, u16)>,
+        plain: ::std::collections::HashMap<u8, u16>,
+    }
+}
+
+macro_attr! {
+    #[derive(Dummy!)]
+    #[allow(dead_code)]
+    struct Tuple<F: Fn(u8) -> u8, T = u8>(#[macro_attr(skip)] pub F, #[allow(unused)] T) where T: Copy;
+}
+
+macro_attr! {
+    #[derive(Dummy!, Clone, Copy)]
+    pub(crate) struct Generic<'a, T: ?Sized> where T: PartialEq<Vec<Vec<u8>>> {
+        #[Dummy!]
+        pub value: &'a T,
+    }
+}
+
+macro_attr! {
+    #[derive(Debug, Dummy!, PartialEq)]
+    #[repr(u8)]
+    enum Variants {
+        #[macro_attr(first)]
+        Unit = 1,
+        Tuple(#[Dummy!(x)] u8, u16) = 2,
+        #[allow(dead_code)]
+        Named {
+            #[macro_attr]
+            #[allow(dead_code)]
+            a: u8,
+        } = 3,
+    }
+}
+
+macro_attr! {
+    #[derive(Dummy!)]
+    union Union {
+        #[macro_attr(x)]
+        a: u8,
+        b: u16,
+    }
+}
+
+#[test]
+fn test_helper_attrs() {
+    let x = Named::default();
+    assert_eq!(x.hidden, 0);
+    assert_eq!(Named::field_names(), &["Visible", "plain"]);
+    let t = Tuple(|x| x + 1, 0u8);
+    assert_eq!((t.0)(1), 2);
+    let g = Generic { value: &vec![vec![1u8]] };
+    assert!(*g.value == vec![vec![1u8]]);
+    assert_eq!(Variants::Unit, Variants::Unit);
+    assert_ne!(Variants::Tuple(1, 2), Variants::Named { a: 1 });
+    let u = Union { b: 1 };
+    let _ = unsafe { u.a };
+}
@@ -0,0 +1,66 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::{macro_attr, macro_attr_bundle};
+
+trait TypeName {
+    fn type_name() -> &'static str;
+}
+
+macro_rules! TypeName {
+    (($($prefix:literal)?) $vis:vis $kw:ident $name:ident $($tail:tt)+) => {
+        impl TypeName for $name {
+            fn type_name() -> &'static str { concat!($($prefix,)? stringify!($name)) }
+        }
+    };
+}
+
+macro_rules! Siblings {
+    (
+        @derives [$($(#[$std_cfg:meta])? $std:path),*] [$($(#[$macro_cfg:meta])? $macros:ident !),*]
+        () $vis:vis $kw:ident $name:ident $($tail:tt)+
+    ) => {
+        impl $name {
+            const SIBLINGS: &'static [&'static str] = &[$(stringify!($std),)* $(stringify!($macros),)*];
+        }
+    };
+}
+
+macro_attr_bundle! {
+    Ordinal = PartialEq, Eq, PartialOrd, Ord;
+}
+
+macro_attr! {
+    #[derive(Clone, Debug if test, Missing! if not(test), TypeName!("on ") if all(test, not(any())), NotATrait if any())]
+    struct Inline;
+}
+
+macro_attr! {
+    #[derive(TypeName! if test, @bundle Ordinal! if test, @derives Siblings!, Copy if test, Clone)]
+    struct Mixed(u8);
+}
+
+macro_attr! {
+    #[cfg_attr(test, derive(Debug if test, Missing! if any(), TypeName!))]
+    #[cfg_attr(not(test), derive(Missing! if test))]
+    struct Grouped;
+}
+
+#[test]
+fn test_inline_cfg() {
+    assert_eq!(format!("{:?}", Inline.clone()), "Inline");
+    assert_eq!(Inline::type_name(), "on Inline");
+    assert_eq!(Mixed::type_name(), "Mixed");
+    let a = Mixed(1);
+    assert!(a < Mixed(2));
+    assert_eq!(Mixed::SIBLINGS, &["PartialEq", "Eq", "PartialOrd", "Ord", "Copy", "Clone", "TypeName", "Siblings"]);
+    assert_eq!(format!("{:?}", Grouped), "Grouped");
+    assert_eq!(Grouped::type_name(), "Grouped");
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::macro_attr;
+use std::sync::OnceLock;
+
+macro_rules! Memoize {
+    (@kind [fn] ($cached:ident) $vis:vis fn $name:ident() -> $ret:ty $body:block) => {
+        $vis fn $cached() -> $ret {
+            static VALUE: OnceLock<$ret> = OnceLock::new();
+            *VALUE.get_or_init($name)
+        }
+    };
+}
+
+trait Registered {
+    const INTERFACE: &'static str;
+}
+
+macro_rules! Register {
+    (@kind [impl] () $(unsafe)? impl $interface:ident for $ty:ty { $($body:tt)* }) => {
+        impl Registered for $ty {
+            const INTERFACE: &'static str = stringify!($interface);
+        }
+    };
+}
+
+macro_rules! KindOf {
+    (@kind [$kind:ident] ($name:ident) $($item:tt)*) => {
+        const $name: &str = stringify!($kind);
+    };
+}
+
+trait Answer {
+    fn answer(&self) -> u32;
+}
+
+struct Deep;
+
+struct Thought;
+
+macro_attr! {
+    #[derive(@kind Memoize!(cached_answer))]
+    fn answer() -> u32 { 42 }
+
+    #[derive(@kind Register!)]
+    impl Answer for Deep {
+        fn answer(&self) -> u32 { cached_answer() }
+    }
+
+    #[derive(@kind Register!)]
+    unsafe impl Send for Thought { }
+}
+
+macro_attr! {
+    #[derive(@kind KindOf!(CONST_UNSAFE_FN))]
+    const unsafe fn const_unsafe_fn() { }
+
+    #[derive(@kind KindOf!(ASYNC_FN))]
+    pub(crate) async fn async_fn() { }
+
+    #[derive(@kind KindOf!(UNSAFE_EXTERN_FN))]
+    unsafe extern "C" fn unsafe_extern_fn() { }
+
+    #[derive(@kind KindOf!(EXTERN_FN))]
+    extern fn extern_fn() { }
+
+    #[derive(@kind KindOf!(EXTERN_BLOCK))]
+    extern "C" { }
+
+    #[derive(@kind KindOf!(UNION))]
+    #[repr(C)]
+    pub union Bits { a: u8, b: i8 }
+
+    #[derive(@kind KindOf!(TYPE))]
+    pub type Alias = Bits;
+
+    #[derive(@kind KindOf!(STATIC_MUT))]
+    static mut COUNTER: u8 = 0;
+
+    #[derive(@kind KindOf!(MOD))]
+    pub mod inner { }
+
+    #[derive(@kind KindOf!(USE))]
+    use std::fmt::Write;
+
+    #[derive(@kind KindOf!(EXTERN_CRATE))]
+    extern crate core as _;
+
+    #[derive(@kind KindOf!(MACRO_RULES))]
+    macro_rules! noop { () => { } }
+}
+
+macro_attr! {
+    #[derive(@kind KindOf!(MACRO_CALL))]
+    noop! { }
+}
+
+#[test]
+fn test_item_kinds() {
+    assert_eq!(Deep.answer(), 42);
+    assert_eq!(cached_answer(), 42);
+    assert_eq!(Deep::INTERFACE, "Answer");
+    assert_eq!(Thought::INTERFACE, "Send");
+    noop!();
+    let mut text = String::new();
+    write!(text, "{}", UNION).unwrap();
+    assert_eq!(
+        [CONST_UNSAFE_FN, ASYNC_FN, UNSAFE_EXTERN_FN, EXTERN_FN, EXTERN_BLOCK, UNION, TYPE, STATIC_MUT],
+        ["fn", "fn", "fn", "fn", "extern", "union", "type", "static"]
+    );
+    assert_eq!([MOD, USE, EXTERN_CRATE, MACRO_RULES], ["mod", "use", "extern_crate", "macro_rules"]);
+    assert_eq!(MACRO_CALL, "item");
+}
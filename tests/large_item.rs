@@ -0,0 +1,687 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+// A large documented item with many derives, expanded with the default `recursion_limit`.
+
+use macro_attr_2018::macro_attr;
+
+macro_rules! Count {
+    (() $vis:vis enum $name:ident { $($(#[$($attr:tt)*])* $variant:ident $(($($tuple:tt)*))? $({ $($named:tt)* })?),* $(,)? }) => {
+        impl $name {
+            pub const COUNT: usize = [$(stringify!($variant)),*].len();
+        }
+    };
+}
+
+macro_rules! Dummy {
+    ($($tts:tt)*) => { };
+}
+
+macro_attr! {
+    /// Line 0 of a long item documentation.
+    /// Line 1 of a long item documentation.
+    /// Line 2 of a long item documentation.
+    /// Line 3 of a long item documentation.
+    /// Line 4 of a long item documentation.
+    /// Line 5 of a long item documentation.
+    /// Line 6 of a long item documentation.
+    /// Line 7 of a long item documentation.
+    /// Line 8 of a long item documentation.
+    /// Line 9 of a long item documentation.
+    /// Line 10 of a long item documentation.
+    /// Line 11 of a long item documentation.
+    /// Line 12 of a long item documentation.
+    /// Line 13 of a long item documentation.
+    /// Line 14 of a long item documentation.
+    /// Line 15 of a long item documentation.
+    /// Line 16 of a long item documentation.
+    /// Line 17 of a long item documentation.
+    /// Line 18 of a long item documentation.
+    /// Line 19 of a long item documentation.
+    /// Line 20 of a long item documentation.
+    /// Line 21 of a long item documentation.
+    /// Line 22 of a long item documentation.
+    /// Line 23 of a long item documentation.
+    /// Line 24 of a long item documentation.
+    /// Line 25 of a long item documentation.
+    /// Line 26 of a long item documentation.
+    /// Line 27 of a long item documentation.
+    /// Line 28 of a long item documentation.
+    /// Line 29 of a long item documentation.
+    /// Line 30 of a long item documentation.
+    /// Line 31 of a long item documentation.
+    /// Line 32 of a long item documentation.
+    /// Line 33 of a long item documentation.
+    /// Line 34 of a long item documentation.
+    /// Line 35 of a long item documentation.
+    /// Line 36 of a long item documentation.
+    /// Line 37 of a long item documentation.
+    /// Line 38 of a long item documentation.
+    /// Line 39 of a long item documentation.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[derive(Count!, Dummy!, Dummy!(1), Dummy!(2), Dummy!(3), Dummy!(4), Dummy!(5), Dummy!(6))]
+    #[derive(@attrs Dummy!, @derives Dummy!, @parsed Dummy!)]
+    #[allow(dead_code)]
+    #[repr(u16)]
+    enum Large {
+        /// Variant 0.
+        V0,
+        /// Variant 1.
+        V1(u8, #[allow(unused)] u16),
+        /// Variant 2.
+        V2 { a: u8, b: u16 },
+        /// Variant 3.
+        V3,
+        /// Variant 4.
+        V4(u8, u16),
+        /// Variant 5.
+        V5 { a: u8, b: u16 },
+        /// Variant 6.
+        V6,
+        /// Variant 7.
+        V7(u8, u16),
+        /// Variant 8.
+        V8 { a: u8, b: u16 },
+        /// Variant 9.
+        V9,
+        /// Variant 10.
+        #[macro_attr(skip)]
+        V10(u8, #[Count!(x)] u16),
+        /// Variant 11.
+        V11 { a: u8, b: u16 },
+        /// Variant 12.
+        V12,
+        /// Variant 13.
+        V13(u8, u16),
+        /// Variant 14.
+        V14 { a: u8, b: u16 },
+        /// Variant 15.
+        V15,
+        /// Variant 16.
+        V16(u8, u16),
+        /// Variant 17.
+        V17 { a: u8, b: u16 },
+        /// Variant 18.
+        V18,
+        /// Variant 19.
+        V19(u8, u16),
+        /// Variant 20.
+        V20 { a: u8, b: u16 },
+        /// Variant 21.
+        V21,
+        /// Variant 22.
+        V22(u8, u16),
+        /// Variant 23.
+        V23 { a: u8, b: u16 },
+        /// Variant 24.
+        V24,
+        /// Variant 25.
+        V25(u8, u16),
+        /// Variant 26.
+        V26 { a: u8, b: u16 },
+        /// Variant 27.
+        V27,
+        /// Variant 28.
+        V28(u8, u16),
+        /// Variant 29.
+        V29 { a: u8, b: u16 },
+        /// Variant 30.
+        V30,
+        /// Variant 31.
+        V31(u8, u16),
+        /// Variant 32.
+        V32 { a: u8, b: u16 },
+        /// Variant 33.
+        V33,
+        /// Variant 34.
+        V34(u8, u16),
+        /// Variant 35.
+        V35 { a: u8, b: u16 },
+        /// Variant 36.
+        V36,
+        /// Variant 37.
+        V37(u8, u16),
+        /// Variant 38.
+        V38 { a: u8, b: u16 },
+        /// Variant 39.
+        V39,
+        /// Variant 40.
+        V40(u8, u16),
+        /// Variant 41.
+        V41 { a: u8, b: u16 },
+        /// Variant 42.
+        V42,
+        /// Variant 43.
+        V43(u8, u16),
+        /// Variant 44.
+        V44 { a: u8, b: u16 },
+        /// Variant 45.
+        V45,
+        /// Variant 46.
+        V46(u8, u16),
+        /// Variant 47.
+        V47 { a: u8, b: u16 },
+        /// Variant 48.
+        V48,
+        /// Variant 49.
+        V49(u8, u16),
+        /// Variant 50.
+        V50 { a: u8, b: u16 },
+        /// Variant 51.
+        V51,
+        /// Variant 52.
+        V52(u8, u16),
+        /// Variant 53.
+        V53 { a: u8, b: u16 },
+        /// Variant 54.
+        V54,
+        /// Variant 55.
+        V55(u8, u16),
+        /// Variant 56.
+        V56 { a: u8, b: u16 },
+        /// Variant 57.
+        V57,
+        /// Variant 58.
+        V58(u8, u16),
+        /// Variant 59.
+        V59 { a: u8, b: u16 },
+        /// Variant 60.
+        V60,
+        /// Variant 61.
+        V61(u8, u16),
+        /// Variant 62.
+        V62 { a: u8, b: u16 },
+        /// Variant 63.
+        V63,
+        /// Variant 64.
+        V64(u8, u16),
+        /// Variant 65.
+        V65 { a: u8, b: u16 },
+        /// Variant 66.
+        V66,
+        /// Variant 67.
+        V67(u8, u16),
+        /// Variant 68.
+        V68 { a: u8, b: u16 },
+        /// Variant 69.
+        V69,
+        /// Variant 70.
+        V70(u8, u16),
+        /// Variant 71.
+        V71 { a: u8, b: u16 },
+        /// Variant 72.
+        V72,
+        /// Variant 73.
+        V73(u8, u16),
+        /// Variant 74.
+        V74 { a: u8, b: u16 },
+        /// Variant 75.
+        V75,
+        /// Variant 76.
+        V76(u8, u16),
+        /// Variant 77.
+        V77 { a: u8, b: u16 },
+        /// Variant 78.
+        V78,
+        /// Variant 79.
+        V79(u8, u16),
+        /// Variant 80.
+        V80 { a: u8, b: u16 },
+        /// Variant 81.
+        V81,
+        /// Variant 82.
+        V82(u8, u16),
+        /// Variant 83.
+        V83 { a: u8, b: u16 },
+        /// Variant 84.
+        V84,
+        /// Variant 85.
+        V85(u8, u16),
+        /// Variant 86.
+        V86 { a: u8, b: u16 },
+        /// Variant 87.
+        V87,
+        /// Variant 88.
+        V88(u8, u16),
+        /// Variant 89.
+        V89 { a: u8, b: u16 },
+        /// Variant 90.
+        V90,
+        /// Variant 91.
+        V91(u8, u16),
+        /// Variant 92.
+        V92 { a: u8, b: u16 },
+        /// Variant 93.
+        V93,
+        /// Variant 94.
+        V94(u8, u16),
+        /// Variant 95.
+        V95 { a: u8, b: u16 },
+        /// Variant 96.
+        V96,
+        /// Variant 97.
+        V97(u8, u16),
+        /// Variant 98.
+        V98 { a: u8, b: u16 },
+        /// Variant 99.
+        V99,
+        /// Variant 100.
+        V100(u8, u16),
+        /// Variant 101.
+        V101 { a: u8, b: u16 },
+        /// Variant 102.
+        V102,
+        /// Variant 103.
+        V103(u8, u16),
+        /// Variant 104.
+        V104 { a: u8, b: u16 },
+        /// Variant 105.
+        V105,
+        /// Variant 106.
+        V106(u8, u16),
+        /// Variant 107.
+        V107 { a: u8, b: u16 },
+        /// Variant 108.
+        V108,
+        /// Variant 109.
+        V109(u8, u16),
+        /// Variant 110.
+        V110 { a: u8, b: u16 },
+        /// Variant 111.
+        V111,
+        /// Variant 112.
+        V112(u8, u16),
+        /// Variant 113.
+        V113 { a: u8, b: u16 },
+        /// Variant 114.
+        V114,
+        /// Variant 115.
+        V115(u8, u16),
+        /// Variant 116.
+        V116 { a: u8, b: u16 },
+        /// Variant 117.
+        V117,
+        /// Variant 118.
+        V118(u8, u16),
+        /// Variant 119.
+        V119 { a: u8, b: u16 },
+        /// Variant 120.
+        V120,
+        /// Variant 121.
+        V121(u8, u16),
+        /// Variant 122.
+        V122 { a: u8, b: u16 },
+        /// Variant 123.
+        V123,
+        /// Variant 124.
+        V124(u8, u16),
+        /// Variant 125.
+        V125 { a: u8, b: u16 },
+        /// Variant 126.
+        V126,
+        /// Variant 127.
+        V127(u8, u16),
+        /// Variant 128.
+        V128 { a: u8, b: u16 },
+        /// Variant 129.
+        V129,
+        /// Variant 130.
+        V130(u8, u16),
+        /// Variant 131.
+        V131 { a: u8, b: u16 },
+        /// Variant 132.
+        V132,
+        /// Variant 133.
+        V133(u8, u16),
+        /// Variant 134.
+        V134 { a: u8, b: u16 },
+        /// Variant 135.
+        V135,
+        /// Variant 136.
+        V136(u8, u16),
+        /// Variant 137.
+        V137 { a: u8, b: u16 },
+        /// Variant 138.
+        V138,
+        /// Variant 139.
+        V139(u8, u16),
+        /// Variant 140.
+        V140 { a: u8, b: u16 },
+        /// Variant 141.
+        V141,
+        /// Variant 142.
+        V142(u8, u16),
+        /// Variant 143.
+        V143 { a: u8, b: u16 },
+        /// Variant 144.
+        V144,
+        /// Variant 145.
+        V145(u8, u16),
+        /// Variant 146.
+        V146 { a: u8, b: u16 },
+        /// Variant 147.
+        V147,
+        /// Variant 148.
+        V148(u8, u16),
+        /// Variant 149.
+        V149 { a: u8, b: u16 },
+        /// Variant 150.
+        V150,
+        /// Variant 151.
+        V151(u8, u16),
+        /// Variant 152.
+        V152 { a: u8, b: u16 },
+        /// Variant 153.
+        V153,
+        /// Variant 154.
+        V154(u8, u16),
+        /// Variant 155.
+        V155 { a: u8, b: u16 },
+        /// Variant 156.
+        V156,
+        /// Variant 157.
+        V157(u8, u16),
+        /// Variant 158.
+        V158 { a: u8, b: u16 },
+        /// Variant 159.
+        V159,
+        /// Variant 160.
+        V160(u8, u16),
+        /// Variant 161.
+        V161 { a: u8, b: u16 },
+        /// Variant 162.
+        V162,
+        /// Variant 163.
+        V163(u8, u16),
+        /// Variant 164.
+        V164 { a: u8, b: u16 },
+        /// Variant 165.
+        V165,
+        /// Variant 166.
+        V166(u8, u16),
+        /// Variant 167.
+        V167 { a: u8, b: u16 },
+        /// Variant 168.
+        V168,
+        /// Variant 169.
+        V169(u8, u16),
+        /// Variant 170.
+        V170 { a: u8, b: u16 },
+        /// Variant 171.
+        V171,
+        /// Variant 172.
+        V172(u8, u16),
+        /// Variant 173.
+        V173 { a: u8, b: u16 },
+        /// Variant 174.
+        V174,
+        /// Variant 175.
+        V175(u8, u16),
+        /// Variant 176.
+        V176 { a: u8, b: u16 },
+        /// Variant 177.
+        V177,
+        /// Variant 178.
+        V178(u8, u16),
+        /// Variant 179.
+        V179 { a: u8, b: u16 },
+        /// Variant 180.
+        V180,
+        /// Variant 181.
+        V181(u8, u16),
+        /// Variant 182.
+        V182 { a: u8, b: u16 },
+        /// Variant 183.
+        V183,
+        /// Variant 184.
+        V184(u8, u16),
+        /// Variant 185.
+        V185 { a: u8, b: u16 },
+        /// Variant 186.
+        V186,
+        /// Variant 187.
+        V187(u8, u16),
+        /// Variant 188.
+        V188 { a: u8, b: u16 },
+        /// Variant 189.
+        V189,
+        /// Variant 190.
+        V190(u8, u16),
+        /// Variant 191.
+        V191 { a: u8, b: u16 },
+        /// Variant 192.
+        V192,
+        /// Variant 193.
+        V193(u8, u16),
+        /// Variant 194.
+        V194 { a: u8, b: u16 },
+        /// Variant 195.
+        V195,
+        /// Variant 196.
+        V196(u8, u16),
+        /// Variant 197.
+        V197 { a: u8, b: u16 },
+        /// Variant 198.
+        V198,
+        /// Variant 199.
+        V199(u8, u16),
+        /// Variant 200.
+        V200 {
+            #[allow(unused)]
+            a: u8,
+            #[Dummy!]
+            b: u16,
+        },
+        /// Variant 201.
+        V201,
+        /// Variant 202.
+        V202(u8, u16),
+        /// Variant 203.
+        V203 { a: u8, b: u16 },
+        /// Variant 204.
+        V204,
+        /// Variant 205.
+        V205(u8, u16),
+        /// Variant 206.
+        V206 { a: u8, b: u16 },
+        /// Variant 207.
+        V207,
+        /// Variant 208.
+        V208(u8, u16),
+        /// Variant 209.
+        V209 { a: u8, b: u16 },
+        /// Variant 210.
+        V210,
+        /// Variant 211.
+        V211(u8, u16),
+        /// Variant 212.
+        V212 { a: u8, b: u16 },
+        /// Variant 213.
+        V213,
+        /// Variant 214.
+        V214(u8, u16),
+        /// Variant 215.
+        V215 { a: u8, b: u16 },
+        /// Variant 216.
+        V216,
+        /// Variant 217.
+        V217(u8, u16),
+        /// Variant 218.
+        V218 { a: u8, b: u16 },
+        /// Variant 219.
+        V219,
+        /// Variant 220.
+        V220(u8, u16),
+        /// Variant 221.
+        V221 { a: u8, b: u16 },
+        /// Variant 222.
+        V222,
+        /// Variant 223.
+        V223(u8, u16),
+        /// Variant 224.
+        V224 { a: u8, b: u16 },
+        /// Variant 225.
+        V225,
+        /// Variant 226.
+        V226(u8, u16),
+        /// Variant 227.
+        V227 { a: u8, b: u16 },
+        /// Variant 228.
+        V228,
+        /// Variant 229.
+        V229(u8, u16),
+        /// Variant 230.
+        V230 { a: u8, b: u16 },
+        /// Variant 231.
+        V231,
+        /// Variant 232.
+        V232(u8, u16),
+        /// Variant 233.
+        V233 { a: u8, b: u16 },
+        /// Variant 234.
+        V234,
+        /// Variant 235.
+        V235(u8, u16),
+        /// Variant 236.
+        V236 { a: u8, b: u16 },
+        /// Variant 237.
+        V237,
+        /// Variant 238.
+        V238(u8, u16),
+        /// Variant 239.
+        V239 { a: u8, b: u16 },
+        /// Variant 240.
+        V240,
+        /// Variant 241.
+        V241(u8, u16),
+        /// Variant 242.
+        V242 { a: u8, b: u16 },
+        /// Variant 243.
+        V243,
+        /// Variant 244.
+        V244(u8, u16),
+        /// Variant 245.
+        V245 { a: u8, b: u16 },
+        /// Variant 246.
+        V246,
+        /// Variant 247.
+        V247(u8, u16),
+        /// Variant 248.
+        V248 { a: u8, b: u16 },
+        /// Variant 249.
+        V249,
+        /// Variant 250.
+        V250(u8, u16),
+        /// Variant 251.
+        V251 { a: u8, b: u16 },
+        /// Variant 252.
+        V252,
+        /// Variant 253.
+        V253(u8, u16),
+        /// Variant 254.
+        V254 { a: u8, b: u16 },
+        /// Variant 255.
+        V255,
+        /// Variant 256.
+        V256(u8, u16),
+        /// Variant 257.
+        V257 { a: u8, b: u16 },
+        /// Variant 258.
+        V258,
+        /// Variant 259.
+        V259(u8, u16),
+        /// Variant 260.
+        V260 { a: u8, b: u16 },
+        /// Variant 261.
+        V261,
+        /// Variant 262.
+        V262(u8, u16),
+        /// Variant 263.
+        V263 { a: u8, b: u16 },
+        /// Variant 264.
+        V264,
+        /// Variant 265.
+        V265(u8, u16),
+        /// Variant 266.
+        V266 { a: u8, b: u16 },
+        /// Variant 267.
+        V267,
+        /// Variant 268.
+        V268(u8, u16),
+        /// Variant 269.
+        V269 { a: u8, b: u16 },
+        /// Variant 270.
+        V270,
+        /// Variant 271.
+        V271(u8, u16),
+        /// Variant 272.
+        V272 { a: u8, b: u16 },
+        /// Variant 273.
+        V273,
+        /// Variant 274.
+        V274(u8, u16),
+        /// Variant 275.
+        V275 { a: u8, b: u16 },
+        /// Variant 276.
+        V276,
+        /// Variant 277.
+        V277(u8, u16),
+        /// Variant 278.
+        V278 { a: u8, b: u16 },
+        /// Variant 279.
+        V279,
+        /// Variant 280.
+        V280(u8, u16),
+        /// Variant 281.
+        V281 { a: u8, b: u16 },
+        /// Variant 282.
+        V282,
+        /// Variant 283.
+        V283(u8, u16),
+        /// Variant 284.
+        V284 { a: u8, b: u16 },
+        /// Variant 285.
+        V285,
+        /// Variant 286.
+        V286(u8, u16),
+        /// Variant 287.
+        V287 { a: u8, b: u16 },
+        /// Variant 288.
+        V288,
+        /// Variant 289.
+        V289(u8, u16),
+        /// Variant 290.
+        V290 { a: u8, b: u16 },
+        /// Variant 291.
+        V291,
+        /// Variant 292.
+        V292(u8, u16),
+        /// Variant 293.
+        V293 { a: u8, b: u16 },
+        /// Variant 294.
+        V294,
+        /// Variant 295.
+        V295(u8, u16),
+        /// Variant 296.
+        V296 { a: u8, b: u16 },
+        /// Variant 297.
+        V297,
+        /// Variant 298.
+        V298(u8, u16),
+        /// Variant 299.
+        V299 { a: u8, b: u16 },
+    }
+}
+
+#[test]
+fn test_large_item() {
+    assert_eq!(Large::COUNT, 300);
+    assert_ne!(Large::V0, Large::V3);
+}
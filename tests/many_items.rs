@@ -0,0 +1,491 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+// Many annotated items in one block, expanded with the default `recursion_limit`.
+
+use macro_attr_2018::macro_attr;
+
+trait TypeName {
+    fn type_name() -> &'static str;
+}
+
+macro_rules! TypeName {
+    (() $vis:vis $kw:ident $name:ident $($tail:tt)*) => {
+        impl TypeName for $name {
+            fn type_name() -> &'static str { stringify!($name) }
+        }
+    };
+}
+
+macro_rules! Returns {
+    (() $vis:vis fn $name:ident() -> $ret:ty { $value:expr }) => {
+        impl Values {
+            $vis fn $name() -> $ret { $value + 1 }
+        }
+    };
+}
+
+pub struct Values;
+
+macro_attr! {
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S0 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S1(u8);
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S2;
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub enum S3 { A, B }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S4 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S5(u8);
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S6;
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub enum S7 { A, B }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S8 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S9(u8);
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S10;
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub enum S11 { A, B }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S12 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S13(u8);
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S14;
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub enum S15 { A, B }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S16 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S17(u8);
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S18;
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub enum S19 { A, B }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S20 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S21(u8);
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct S22;
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub enum S23 { A, B }
+}
+
+macro_attr! {
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B0 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B1 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B2 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B3 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B4 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B5 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B6 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B7 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B8 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B9 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B10 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B11 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B12 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B13 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B14 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B15 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B16 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B17 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B18 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B19 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B20 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B21 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B22 { a: u8 }
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct B23 { a: u8 }
+}
+
+macro_attr! {
+    #[derive(Returns!)]
+    pub fn f0() -> u8 { 0 }
+
+    pub const C0: u16 = 0;
+
+    pub static S0_VALUE: [u8; 2] = [0, 1];
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct Typed0 { pub a: u8, pub b: Option<u16> }
+
+    #[allow(dead_code)]
+    pub fn f1() -> u8 { 1 }
+
+    #[derive(Returns!)]
+    pub fn f2() -> u8 { 2 }
+
+    #[allow(dead_code)]
+    pub fn f3() -> u8 { 3 }
+
+    #[derive(Returns!)]
+    pub fn f4() -> u8 { 4 }
+
+    pub const C1: u16 = 2;
+
+    pub static S1_VALUE: [u8; 2] = [1, 1];
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct Typed1 { pub a: u8, pub b: Option<u16> }
+
+    #[allow(dead_code)]
+    pub fn f5() -> u8 { 5 }
+
+    #[derive(Returns!)]
+    pub fn f6() -> u8 { 6 }
+
+    #[allow(dead_code)]
+    pub fn f7() -> u8 { 7 }
+
+    #[derive(Returns!)]
+    pub fn f8() -> u8 { 8 }
+
+    pub const C2: u16 = 4;
+
+    pub static S2_VALUE: [u8; 2] = [2, 1];
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct Typed2 { pub a: u8, pub b: Option<u16> }
+
+    #[allow(dead_code)]
+    pub fn f9() -> u8 { 9 }
+
+    #[derive(Returns!)]
+    pub fn f10() -> u8 { 10 }
+
+    #[allow(dead_code)]
+    pub fn f11() -> u8 { 11 }
+
+    #[derive(Returns!)]
+    pub fn f12() -> u8 { 12 }
+
+    pub const C3: u16 = 6;
+
+    pub static S3_VALUE: [u8; 2] = [3, 1];
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct Typed3 { pub a: u8, pub b: Option<u16> }
+
+    #[allow(dead_code)]
+    pub fn f13() -> u8 { 13 }
+
+    #[derive(Returns!)]
+    pub fn f14() -> u8 { 14 }
+
+    #[allow(dead_code)]
+    pub fn f15() -> u8 { 15 }
+
+    #[derive(Returns!)]
+    pub fn f16() -> u8 { 16 }
+
+    pub const C4: u16 = 8;
+
+    pub static S4_VALUE: [u8; 2] = [4, 1];
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct Typed4 { pub a: u8, pub b: Option<u16> }
+
+    #[allow(dead_code)]
+    pub fn f17() -> u8 { 17 }
+
+    #[derive(Returns!)]
+    pub fn f18() -> u8 { 18 }
+
+    #[allow(dead_code)]
+    pub fn f19() -> u8 { 19 }
+
+    #[derive(Returns!)]
+    pub fn f20() -> u8 { 20 }
+
+    pub const C5: u16 = 10;
+
+    pub static S5_VALUE: [u8; 2] = [5, 1];
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct Typed5 { pub a: u8, pub b: Option<u16> }
+
+    #[allow(dead_code)]
+    pub fn f21() -> u8 { 21 }
+
+    #[derive(Returns!)]
+    pub fn f22() -> u8 { 22 }
+
+    #[allow(dead_code)]
+    pub fn f23() -> u8 { 23 }
+
+    #[derive(Returns!)]
+    pub fn f24() -> u8 { 24 }
+
+    pub const C6: u16 = 12;
+
+    pub static S6_VALUE: [u8; 2] = [6, 1];
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct Typed6 { pub a: u8, pub b: Option<u16> }
+
+    #[allow(dead_code)]
+    pub fn f25() -> u8 { 25 }
+
+    #[derive(Returns!)]
+    pub fn f26() -> u8 { 26 }
+
+    #[allow(dead_code)]
+    pub fn f27() -> u8 { 27 }
+
+    #[derive(Returns!)]
+    pub fn f28() -> u8 { 28 }
+
+    pub const C7: u16 = 14;
+
+    pub static S7_VALUE: [u8; 2] = [7, 1];
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct Typed7 { pub a: u8, pub b: Option<u16> }
+
+    #[allow(dead_code)]
+    pub fn f29() -> u8 { 29 }
+
+    #[derive(Returns!)]
+    pub fn f30() -> u8 { 30 }
+
+    #[allow(dead_code)]
+    pub fn f31() -> u8 { 31 }
+
+    #[derive(Returns!)]
+    pub fn f32() -> u8 { 32 }
+
+    pub const C8: u16 = 16;
+
+    pub static S8_VALUE: [u8; 2] = [8, 1];
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct Typed8 { pub a: u8, pub b: Option<u16> }
+
+    #[allow(dead_code)]
+    pub fn f33() -> u8 { 33 }
+
+    #[derive(Returns!)]
+    pub fn f34() -> u8 { 34 }
+
+    #[allow(dead_code)]
+    pub fn f35() -> u8 { 35 }
+
+    #[derive(Returns!)]
+    pub fn f36() -> u8 { 36 }
+
+    pub const C9: u16 = 18;
+
+    pub static S9_VALUE: [u8; 2] = [9, 1];
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct Typed9 { pub a: u8, pub b: Option<u16> }
+
+    #[allow(dead_code)]
+    pub fn f37() -> u8 { 37 }
+
+    #[derive(Returns!)]
+    pub fn f38() -> u8 { 38 }
+
+    #[allow(dead_code)]
+    pub fn f39() -> u8 { 39 }
+
+    #[derive(Returns!)]
+    pub fn f40() -> u8 { 40 }
+
+    pub const C10: u16 = 20;
+
+    pub static S10_VALUE: [u8; 2] = [10, 1];
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct Typed10 { pub a: u8, pub b: Option<u16> }
+
+    #[allow(dead_code)]
+    pub fn f41() -> u8 { 41 }
+
+    #[derive(Returns!)]
+    pub fn f42() -> u8 { 42 }
+
+    #[allow(dead_code)]
+    pub fn f43() -> u8 { 43 }
+
+    #[derive(Returns!)]
+    pub fn f44() -> u8 { 44 }
+
+    pub const C11: u16 = 22;
+
+    pub static S11_VALUE: [u8; 2] = [11, 1];
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct Typed11 { pub a: u8, pub b: Option<u16> }
+
+    #[allow(dead_code)]
+    pub fn f45() -> u8 { 45 }
+
+    #[derive(Returns!)]
+    pub fn f46() -> u8 { 46 }
+
+    #[allow(dead_code)]
+    pub fn f47() -> u8 { 47 }
+
+    #[derive(Returns!)]
+    pub fn f48() -> u8 { 48 }
+
+    pub const C12: u16 = 24;
+
+    pub static S12_VALUE: [u8; 2] = [12, 1];
+
+    #[derive(Debug, Clone, TypeName!)]
+    pub struct Typed12 { pub a: u8, pub b: Option<u16> }
+
+    #[allow(dead_code)]
+    pub fn f49() -> u8 { 49 }
+
+    #[derive(Returns!)]
+    pub fn f50() -> u8 { 50 }
+
+    #[allow(dead_code)]
+    pub fn f51() -> u8 { 51 }
+}
+
+#[test]
+fn test_many_items() {
+    let mixed = [
+        S0::type_name(), S1::type_name(), S2::type_name(), S3::type_name(), S4::type_name(), S5::type_name(),
+        S6::type_name(), S7::type_name(), S8::type_name(), S9::type_name(), S10::type_name(), S11::type_name(),
+        S12::type_name(), S13::type_name(), S14::type_name(), S15::type_name(), S16::type_name(), S17::type_name(),
+        S18::type_name(), S19::type_name(), S20::type_name(), S21::type_name(), S22::type_name(), S23::type_name(),
+    ];
+    let braced = [
+        B0::type_name(), B1::type_name(), B2::type_name(), B3::type_name(), B4::type_name(), B5::type_name(),
+        B6::type_name(), B7::type_name(), B8::type_name(), B9::type_name(), B10::type_name(), B11::type_name(),
+        B12::type_name(), B13::type_name(), B14::type_name(), B15::type_name(), B16::type_name(), B17::type_name(),
+        B18::type_name(), B19::type_name(), B20::type_name(), B21::type_name(), B22::type_name(), B23::type_name(),
+    ];
+    for (i, (s, b)) in mixed.iter().zip(braced.iter()).enumerate() {
+        assert_eq!(*s, format!("S{}", i));
+        assert_eq!(*b, format!("B{}", i));
+    }
+}
+
+#[test]
+fn test_many_typed_items() {
+    let fns = [
+        f0(), f1(), f2(), f3(), f4(), f5(), f6(), f7(), f8(), f9(), f10(), f11(), f12(), f13(),
+        f14(), f15(), f16(), f17(), f18(), f19(), f20(), f21(), f22(), f23(), f24(), f25(), f26(),
+        f27(), f28(), f29(), f30(), f31(), f32(), f33(), f34(), f35(), f36(), f37(), f38(), f39(),
+        f40(), f41(), f42(), f43(), f44(), f45(), f46(), f47(), f48(), f49(), f50(), f51()
+    ];
+    assert_eq!(fns.iter().map(|&x| x as usize).sum::<usize>(), (0 .. 52).sum());
+    let values = [
+        Values::f0(), Values::f2(), Values::f4(), Values::f6(), Values::f8(), Values::f10(),
+        Values::f12(), Values::f14(), Values::f16(), Values::f18(), Values::f20(), Values::f22(),
+        Values::f24(), Values::f26(), Values::f28(), Values::f30(), Values::f32(), Values::f34(),
+        Values::f36(), Values::f38(), Values::f40(), Values::f42(), Values::f44(), Values::f46(),
+        Values::f48(), Values::f50()
+    ];
+    assert!(values.iter().enumerate().all(|(i, &x)| x as usize == 2 * i + 1));
+    let consts = [
+        C0, C1, C2, C3, C4, C5, C6, C7, C8, C9, C10, C11, C12
+    ];
+    let statics = [
+        S0_VALUE[0], S1_VALUE[0], S2_VALUE[0], S3_VALUE[0], S4_VALUE[0], S5_VALUE[0], S6_VALUE[0],
+        S7_VALUE[0], S8_VALUE[0], S9_VALUE[0], S10_VALUE[0], S11_VALUE[0], S12_VALUE[0]
+    ];
+    let typed = [
+        Typed0::type_name(), Typed1::type_name(), Typed2::type_name(), Typed3::type_name(),
+        Typed4::type_name(), Typed5::type_name(), Typed6::type_name(), Typed7::type_name(),
+        Typed8::type_name(), Typed9::type_name(), Typed10::type_name(), Typed11::type_name(),
+        Typed12::type_name()
+    ];
+    for (i, ((c, s), t)) in consts.iter().zip(statics.iter()).zip(typed.iter()).enumerate() {
+        assert_eq!(*c as usize, 2 * i);
+        assert_eq!(*s as usize, i);
+        assert_eq!(*t, format!("Typed{}", i));
+    }
+    let typed = Typed0 { a: 1, b: Some(2) }.clone();
+    assert_eq!((typed.a, typed.b), (1, Some(2)));
+}
@@ -0,0 +1,118 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::macro_attr;
+
+trait TypeName {
+    fn type_name() -> &'static str;
+}
+
+macro_rules! TypeName {
+    (() $vis:vis $kw:ident $name:ident $($tail:tt)+) => {
+        impl TypeName for $name {
+            fn type_name() -> &'static str { stringify!($name) }
+        }
+    };
+}
+
+macro_attr! {
+    use std::fmt::Debug;
+
+    #[derive(Debug, TypeName!)]
+    pub struct Unit;
+
+    #[derive(Clone)]
+    struct Tuple<T: Fn(u8) -> u8>(T) where T: Copy;
+
+    #[derive(Clone, Copy, TypeName!)]
+    pub(crate) enum Enum { A, B }
+
+    const C: Tuple<fn(u8) -> u8> = Tuple({ fn id(x: u8) -> u8 { x } id });
+
+    pub const fn c() -> u8 { 3 }
+
+    const _: () = { };
+
+    static S: Unit = Unit;
+
+    type Alias = Enum;
+
+    #[allow(dead_code)]
+    pub(crate) const unsafe fn unsafe_c() -> u8 { 4 }
+
+    impl Unit {
+        fn debug(&self) -> String where Self: Debug { format!("{:?}", self) }
+    }
+
+    mod inner {
+        pub struct Inner;
+    }
+
+    macro_rules! local { () => { 5 } }
+}
+
+macro_rules! Dummy {
+    ($($tts:tt)*) => { };
+}
+
+pub struct Arr<const N: usize>;
+
+macro_attr! {
+    #[derive(Dummy!)]
+    fn make() -> Arr<{ 1 + 2 }> { Arr }
+
+    impl Arr<{ 1 + 2 }> {
+        fn len(&self) -> usize { 3 }
+    }
+
+    impl<const N: usize> Clone for Arr<N> {
+        fn clone(&self) -> Self { Arr }
+    }
+}
+
+pub trait Assoc {
+    type A;
+}
+
+impl Assoc for u8 {
+    type A = u16;
+}
+
+pub trait Named<T> {
+    const NAME: &'static str;
+}
+
+pub struct Pair<T, const N: usize>(pub T);
+
+macro_attr! {
+    #[derive(Dummy!)]
+    impl<T: Assoc> Named<T> for Pair<<T as Assoc>::A, { 1 + 1 }> {
+        const NAME: &'static str = "pair";
+    }
+
+    #[derive(Dummy!)]
+    fn pair_name() -> &'static str { <Pair<u16, 2> as Named<u8>>::NAME }
+}
+
+#[test]
+fn test_multi_items() {
+    assert_eq!(Unit::type_name(), "Unit");
+    assert_eq!((C.clone().0)(1), 1);
+    assert_eq!(Enum::type_name(), "Enum");
+    assert_eq!((C.0)(7), 7);
+    assert_eq!(c(), 3);
+    assert_eq!(S.debug(), "Unit");
+    let _: Alias = Enum::A;
+    assert_eq!(unsafe { unsafe_c() }, 4);
+    let _ = inner::Inner;
+    assert_eq!(local!(), 5);
+    assert_eq!(make().clone().len(), 3);
+    assert_eq!(pair_name(), "pair");
+}
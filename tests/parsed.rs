@@ -0,0 +1,133 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::macro_attr;
+
+trait Shape {
+    fn kind() -> &'static str;
+    fn members() -> &'static [&'static str];
+    fn generics() -> &'static str;
+    fn where_clause() -> &'static str;
+}
+
+macro_rules! Shape {
+    (
+        @parsed {
+            kind: $kind:ident,
+            vis: [$vis:vis],
+            name: $name:ident,
+            generics: [$($params:tt)*],
+            where: [$($where:tt)*],
+            fields: $shape:ident [$({
+                attrs: [$(#[$($field_attrs:tt)*])*],
+                vis: [$field_vis:vis],
+                $(name: $field:ident,)?
+                ty: [$field_ty:ty]
+            })*]
+        }
+        ($([$($impl_params:tt)*] [$($ty_params:tt)*])?) $($item:tt)*
+    ) => {
+        impl<$($($impl_params)*)?> Shape for $name<$($($ty_params)*)?> where $($where)* {
+            fn kind() -> &'static str { concat!(stringify!($kind), " ", stringify!($shape)) }
+            fn members() -> &'static [&'static str] {
+                &[$(concat!($(stringify!($field), ": ",)? stringify!($field_ty))),*]
+            }
+            fn generics() -> &'static str { stringify!($($params)*) }
+            fn where_clause() -> &'static str { stringify!($($where)*) }
+        }
+    };
+    (
+        @parsed {
+            kind: enum,
+            vis: [$vis:vis],
+            name: $name:ident,
+            generics: [$($params:tt)*],
+            where: [$($where:tt)*],
+            variants: [$({
+                attrs: [$(#[$($attrs:tt)*])*],
+                name: $variant:ident,
+                fields: $shape:ident [$($fields:tt)*],
+                discriminant: [$($discriminant:expr)?]
+            })*]
+        }
+        ($([$($impl_params:tt)*] [$($ty_params:tt)*])?) $($item:tt)*
+    ) => {
+        impl<$($($impl_params)*)?> Shape for $name<$($($ty_params)*)?> where $($where)* {
+            fn kind() -> &'static str { "enum" }
+            fn members() -> &'static [&'static str] {
+                &[$(concat!(stringify!($variant), " ", stringify!($shape) $(, " = ", stringify!($discriminant))?)),*]
+            }
+            fn generics() -> &'static str { stringify!($($params)*) }
+            fn where_clause() -> &'static str { stringify!($($where)*) }
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(Debug, @parsed Shape!)]
+    pub struct Named {
+        #[allow(dead_code)]
+        pub a: u8,
+        b: Vec<(u8, u16)>,
+    }
+}
+
+macro_attr! {
+    #[derive(@parsed Shape!([T: Iterator<Item = Vec<u8>>] [T]))]
+    struct Tuple<T: Iterator<Item = Vec<u8>>>(pub(crate) T, #[Shape!(skip)] Option<T>) where T: Clone;
+}
+
+macro_attr! {
+    #[derive(@parsed Shape!)]
+    pub(crate) struct Unit;
+}
+
+macro_attr! {
+    #[derive(Clone, Copy, @parsed Shape!)]
+    #[repr(u8)]
+    enum Variants {
+        #[allow(dead_code)]
+        Unit = 1,
+        Tuple(u8, u16) = 2,
+        Named {
+            #[macro_attr(x)]
+            a: u8,
+        } = 3,
+    }
+}
+
+macro_attr! {
+    #[derive(@parsed Shape!)]
+    union Union {
+        a: u8,
+        b: u16,
+    }
+}
+
+#[test]
+fn test_parsed_structs() {
+    assert_eq!(Named::kind(), "struct named");
+    assert_eq!(Named::members(), &["a: u8", "b: Vec<(u8, u16)>"]);
+    assert_eq!(Named::generics(), "");
+    assert_eq!(<Tuple<std::vec::IntoIter<Vec<u8>>>>::kind(), "struct tuple");
+    assert_eq!(<Tuple<std::vec::IntoIter<Vec<u8>>>>::members(), &["T", "Option<T>"]);
+    assert_eq!(<Tuple<std::vec::IntoIter<Vec<u8>>>>::generics().replace(" ", ""), "T:Iterator<Item=Vec<u8>>");
+    assert_eq!(<Tuple<std::vec::IntoIter<Vec<u8>>>>::where_clause(), "T: Clone");
+    assert_eq!(Unit::kind(), "struct unit");
+    assert!(Unit::members().is_empty());
+    assert_eq!(Union::kind(), "union named");
+}
+
+#[test]
+fn test_parsed_enum() {
+    assert_eq!(Variants::kind(), "enum");
+    assert_eq!(Variants::members(), &["Unit unit = 1", "Tuple tuple = 2", "Named named = 3"]);
+    let _ = (Variants::Tuple(0, 0), Variants::Named { a: 0 });
+}
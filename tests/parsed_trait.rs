@@ -0,0 +1,142 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+#![allow(clippy::missing_safety_doc)]
+
+use macro_attr_2018::macro_attr;
+
+macro_rules! Signatures {
+    (
+        @parsed {
+            kind: trait,
+            vis: [$vis:vis],
+            safety: [$($safety:tt)*],
+            name: $name:ident,
+            generics: [$($params:tt)*],
+            where: [$($where:tt)*],
+            supertraits: [$($supertraits:tt)*],
+            items: [$($item:tt)*]
+        }
+        ($signatures:ident) $($tail:tt)*
+    ) => {
+        const $signatures: [&str; 5] = [
+            concat!(stringify!($($safety)*), " ", stringify!($name), "<", stringify!($($params)*), ">"),
+            stringify!($($supertraits)*),
+            stringify!($($where)*),
+            stringify!($vis),
+            concat!($(Signatures!(@item $item), "|"),*),
+        ];
+    };
+    (
+        @item {
+            attrs: $attrs:tt,
+            kind: type,
+            name: $name:ident,
+            generics: [$($generics:tt)*],
+            bounds: [$($bounds:tt)*],
+            where: [$($where:tt)*]
+        }
+    ) => {
+        concat!(
+            "type ", stringify!($name), "<", stringify!($($generics)*), ">: ", stringify!($($bounds)*),
+            " where ", stringify!($($where)*)
+        )
+    };
+    (@item { attrs: $attrs:tt, kind: const, name: $name:ident, ty: [$ty:ty], default: [$($default:expr)?] }) => {
+        concat!("const ", stringify!($name), ": ", stringify!($ty) $(, " = ", stringify!($default))?)
+    };
+    (
+        @item {
+            attrs: [$(#[$($attrs:tt)*])*],
+            kind: fn,
+            safety: [$($safety:tt)*],
+            name: $name:ident,
+            generics: [$($generics:tt)*],
+            receiver: $receiver:ident [$($self:tt)*],
+            args: [$({ pat: [$($arg:tt)*], ty: [$arg_ty:ty] })*],
+            ret: [$($ret:ty)?],
+            where: [$($where:tt)*],
+            default: [$($default:block)?]
+        }
+    ) => {
+        concat!(
+            $(stringify!(#[$($attrs)*]),)* stringify!($($safety)*), " fn ", stringify!($name), "<", stringify!($($generics)*), ">",
+            "(", stringify!($receiver), " ", stringify!($($self)*), $(", ", stringify!($($arg)*), ": ", stringify!($arg_ty),)* ")",
+            $(" -> ", stringify!($ret),)? " where ", stringify!($($where)*), $(" ", stringify!($default))?
+        )
+    };
+}
+
+macro_attr! {
+    #[derive(@parsed Signatures!(PLAIN))]
+    trait Plain { }
+}
+
+macro_attr! {
+    #[derive(@parsed Signatures!(COMPLEX))]
+    pub(crate) unsafe trait Complex<'a, A: Clone = u8>: Clone + Send where A: 'a {
+        type Item: Iterator<Item = A> + 'a;
+        type Output;
+        const N: usize;
+        const M: u8 = 1 + 2;
+        #[must_use]
+        fn by_ref(&self) -> u8;
+        fn by_mut(&'a mut self, x: u8, y: Vec<A>,) -> Self::Output { let _ = (x, y); unimplemented!() }
+        fn by_value(self);
+        unsafe fn boxed(self: Box<Self>);
+        fn generic<T: Into<Vec<Vec<u8>>>>(&self, t: T) -> usize where Self: Sized;
+        fn associated(x: u8) -> Self where Self: Sized;
+    }
+}
+
+macro_attr! {
+    #[derive(@parsed Signatures!(PATTERNS))]
+    trait Patterns {
+        type Item<'a, T: Clone>: Clone where Self: 'a, T: 'a;
+        fn untyped(&self, _: u8);
+        fn patterns(mut x: u8, _: u8, (a, b): (u8, u16), [c]: [u8; 1]) -> u16 where Self: Sized {
+            x += a + c;
+            u16::from(x) + b
+        }
+    }
+}
+
+fn squash(s: &str) -> String {
+    s.split_whitespace().collect()
+}
+
+#[test]
+fn test_parsed_trait() {
+    assert_eq!(PLAIN.map(squash), ["Plain<>", "", "", "", ""]);
+    assert_eq!(COMPLEX[..4].iter().copied().map(squash).collect::<Vec<_>>(), [
+        "unsafeComplex<'a,A:Clone=u8>",
+        "Clone+Send",
+        "A:'a",
+        "pub(crate)",
+    ]);
+    assert_eq!(COMPLEX[4].split('|').map(squash).collect::<Vec<_>>(), [
+        "typeItem<>:Iterator<Item=A>+'awhere",
+        "typeOutput<>:where",
+        "constN:usize",
+        "constM:u8=1+2",
+        "#[must_use]fnby_ref<>(ref&self)->u8where",
+        "fnby_mut<>(mut&'amutself,x:u8,y:Vec<A>)->Self::Outputwhere{let_=(x,y);unimplemented!()}",
+        "fnby_value<>(valueself)where",
+        "unsafefnboxed<>(typedself:Box<Self>)where",
+        "fngeneric<T:Into<Vec<Vec<u8>>>>(ref&self,t:T)->usizewhereSelf:Sized",
+        "fnassociated<>(none,x:u8)->SelfwhereSelf:Sized",
+        "",
+    ]);
+    assert_eq!(PATTERNS[4].split('|').map(squash).collect::<Vec<_>>(), [
+        "typeItem<'a,T:Clone>:ClonewhereSelf:'a,T:'a",
+        "fnuntyped<>(ref&self,_:u8)where",
+        "fnpatterns<>(none,mutx:u8,_:u8,(a,b):(u8,u16),[c]:[u8;1])->u16whereSelf:Sized{x+=a+c;u16::from(x)+b}",
+        "",
+    ]);
+}
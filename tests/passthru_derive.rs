@@ -14,5 +14,18 @@ macro_attr! {
     pub struct Dummy(u32);
 }
 
+macro_attr! {
+    #[derive(core::fmt::Debug, ::core::clone::Clone, Copy, std::cmp::PartialEq)]
+    #[derive(::std::default::Default)]
+    pub struct PathDummy(u32);
+}
+
 #[test]
 fn test_passthru_derive() {}
+
+#[test]
+fn test_passthru_path_derive() {
+    let x = PathDummy::default();
+    assert_eq!(x.clone(), PathDummy(0));
+    assert_eq!(format!("{:?}", x), "PathDummy(0)");
+}
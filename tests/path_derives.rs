@@ -0,0 +1,64 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::macro_attr;
+
+pub trait TypeName {
+    fn type_name() -> &'static str;
+}
+
+mod derives {
+    macro_rules! TypeName {
+        (() $vis:vis $kw:ident $name:ident $($tail:tt)+) => {
+            impl $crate::TypeName for $name {
+                fn type_name() -> &'static str { stringify!($name) }
+            }
+        };
+    }
+
+    pub(crate) use TypeName;
+}
+
+#[macro_export]
+macro_rules! ExportedTypeName {
+    (() $vis:vis $kw:ident $name:ident $($tail:tt)+) => {
+        impl $crate::TypeName for $name {
+            fn type_name() -> &'static str { concat!("exported ", stringify!($name)) }
+        }
+    };
+}
+
+macro_rules! dollar_crate_derive {
+    ($name:ident) => {
+        macro_attr! {
+            #[derive($crate::ExportedTypeName!)]
+            struct $name;
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(Debug, derives::TypeName!)]
+    struct Relative;
+}
+
+macro_attr! {
+    #[derive(crate::derives::TypeName!(), Clone)]
+    enum Absolute { }
+}
+
+dollar_crate_derive!(DollarCrate);
+
+#[test]
+fn test_path_derives() {
+    assert_eq!(Relative::type_name(), "Relative");
+    assert_eq!(Absolute::type_name(), "Absolute");
+    assert_eq!(DollarCrate::type_name(), "exported DollarCrate");
+}
@@ -0,0 +1,165 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::{macro_attr, macro_attr_bundle};
+
+trait Describe {
+    const KIND: &'static str;
+    const ARGS: &'static str;
+    const ATTRS: &'static [&'static str];
+    const DERIVES: &'static [&'static str];
+    const MACRO_DERIVES: &'static [&'static str];
+    const FIELDS: &'static [&'static str];
+}
+
+macro_rules! DescribeV2 {
+    (
+        @macro_attr v2 {
+            kind: $kind:ident,
+            args: ($($args:tt)*),
+            attrs: [$(#[$($attr:tt)*])*],
+            derives: [$($derive:path),*],
+            macro_derives: [$($macro_derive:ident !),*],
+            recurse: [$($recurse:tt)*],
+            parsed: [$({ $($parsed:tt)* })?],
+        }
+        $vis:vis $item_kind:ident $name:ident $($tail:tt)*
+    ) => {
+        impl Describe for $name {
+            const KIND: &'static str = stringify!($kind);
+            const ARGS: &'static str = stringify!($($args)*);
+            const ATTRS: &'static [&'static str] = &[$(stringify!($($attr)*)),*];
+            const DERIVES: &'static [&'static str] = &[$(stringify!($derive)),*];
+            const MACRO_DERIVES: &'static [&'static str] = &[$(stringify!($macro_derive)),*];
+            const FIELDS: &'static [&'static str] = DescribeV2!(@fields $($($parsed)*)?);
+        }
+    };
+    (@fields kind: $kind:ident, vis: $vis:tt, name: $name:ident, generics: $generics:tt, where: $where:tt,
+        fields: $shape:ident [$({ attrs: $attrs:tt, vis: $field_vis:tt, name: $field:ident, ty: $ty:tt })*]) => {
+        &[$(stringify!($field)),*]
+    };
+    (@fields $($tail:tt)*) => {
+        &[]
+    };
+}
+
+macro_rules! TypeName {
+    (() $vis:vis $kw:ident $name:ident $($tail:tt)*) => {
+        impl $name {
+            const TYPE_NAME: &'static str = stringify!($name);
+        }
+    };
+}
+
+macro_rules! KindOf {
+    (@macro_attr v2 { kind: $kind:ident, args: ($name:ident), $($keys:tt)* } $($item:tt)*) => {
+        const $name: &str = stringify!($kind);
+    };
+}
+
+macro_attr! {
+    /// # Safety
+    ///
+    /// Nothing to uphold.
+    #[derive(@v2 KindOf!(CONST_FN))]
+    pub(crate) const unsafe fn const_fn() { }
+
+    #[derive(@v2 KindOf!(EXTERN_FN))]
+    unsafe extern "C" fn extern_fn() { }
+
+    #[derive(@v2 KindOf!(TRAIT))]
+    trait Marker { }
+
+    #[derive(@v2 KindOf!(IMPL))]
+    impl Marker for Kind { }
+
+    #[derive(@v2 KindOf!(CONST))]
+    const VALUE: u8 = 0;
+
+    #[derive(@v2 KindOf!(STATIC))]
+    static mut STATE: u8 = 0;
+
+    #[derive(@v2 KindOf!(MOD))]
+    mod inner { }
+}
+
+macro_rules! Declared {
+    (@macro_attr v2 { kind: $kind:ident, args: ($name:ident), attrs: $attrs:tt, derives: [$($derive:path),*], $($keys:tt)* } $($item:tt)*) => {
+        const $name: &[&str] = &[stringify!($kind), $(stringify!($derive)),*];
+    };
+    ($($v1:tt)*) => {
+        __macro_attr_v2! { }
+    };
+}
+
+macro_attr_bundle! {
+    Describe = @v2 DescribeV2!;
+}
+
+macro_attr! {
+    #[derive(Clone, TypeName!, Declared!(DECLARED), Debug)]
+    struct Plain;
+
+    #[derive(Declared!(DECLARED_ENUM))]
+    enum PlainEnum { }
+}
+
+macro_attr! {
+    #[derive(Clone, @bundle Describe!, TypeName!)]
+    #[repr(C)]
+    struct Point {
+        x: i32,
+    }
+}
+
+macro_attr! {
+    #[derive(@parsed @v2 DescribeV2!(1, 2), Copy, Clone)]
+    pub(crate) struct Parsed {
+        pub a: u8,
+        b: u16,
+    }
+}
+
+macro_attr! {
+    #[derive(@parsed @recurse @v2 DescribeV2!)]
+    struct Recursed {
+        c: u32,
+    }
+}
+
+macro_attr! {
+    #[derive(@v2 DescribeV2!)]
+    pub enum Kind { A }
+}
+
+#[test]
+fn test_protocol_v2() {
+    assert_eq!(Point::KIND, "struct");
+    assert_eq!(Point::ARGS, "");
+    assert_eq!(Point::ATTRS, &["repr(C)"]);
+    assert_eq!(Point::DERIVES, &["Clone"]);
+    assert_eq!(Point::MACRO_DERIVES, &["DescribeV2", "TypeName"]);
+    assert!(Point::FIELDS.is_empty());
+    assert_eq!(Point::TYPE_NAME, "Point");
+    assert_eq!(Parsed::ARGS, "1, 2");
+    assert_eq!(Parsed::DERIVES, &["Copy", "Clone"]);
+    assert_eq!(Parsed::FIELDS, &["a", "b"]);
+    assert_eq!(Recursed::FIELDS, &["c"]);
+    assert_eq!(Kind::KIND, "enum");
+    assert_eq!(DECLARED, ["struct", "Clone", "Debug"]);
+    assert_eq!(DECLARED_ENUM, ["enum"]);
+    assert_eq!(Plain::TYPE_NAME, "Plain");
+    macro_attr! {
+        #[derive(Declared!(LOCAL), Copy, Clone)]
+        union Local { a: u8 }
+    }
+    assert_eq!(LOCAL, ["union", "Copy", "Clone"]);
+    assert_eq!([CONST_FN, EXTERN_FN, TRAIT, IMPL, CONST, STATIC, MOD], ["fn", "fn", "trait", "impl", "const", "static", "mod"]);
+}
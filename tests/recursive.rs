@@ -0,0 +1,78 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::macro_attr;
+
+trait Describe {
+    fn describe() -> &'static str;
+}
+
+macro_rules! Describe {
+    (() $vis:vis $kw:ident $name:ident $($tail:tt)+) => {
+        impl Describe for $name {
+            fn describe() -> &'static str { concat!(stringify!($kw), " ", stringify!($name)) }
+        }
+    };
+}
+
+macro_rules! Fields {
+    (
+        @recurse $macro_attr:path { $($state:tt)* }
+        ($fields:ident) $vis:vis struct $name:ident { $($field:ident : $ty:ty),* $(,)? }
+    ) => {
+        $macro_attr! {
+            $($state)*
+            #[derive(Debug, Clone, Copy, PartialEq, Describe!)]
+            #[allow(non_camel_case_types)]
+            $vis enum $fields { $($field),* }
+        }
+    };
+}
+
+macro_rules! Builder {
+    (
+        @recurse $macro_attr:path { $($state:tt)* }
+        ($builder:ident $(, $fields:ident)?) $vis:vis struct $name:ident { $($field:ident : $ty:ty),* $(,)? }
+    ) => {
+        $macro_attr! {
+            $($state)*
+            #[derive(Default, Describe! $(, @recurse Fields!($fields))?)]
+            $vis struct $builder { $($field: Option<$ty>),* }
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(@recurse Fields!(PointField), Describe!)]
+    struct Point { x: i32, y: i32 }
+}
+
+macro_attr! {
+    #[derive(Debug, @recurse Builder!(ConfigBuilder, ConfigBuilderField))]
+    pub struct Config { name: &'static str, size: usize }
+}
+
+macro_attr! {
+    #[derive(@recurse(1) Builder!(LimitedBuilder))]
+    struct Limited { value: u8 }
+}
+
+#[test]
+fn test_recursive_derives() {
+    assert_eq!(Point::describe(), "struct Point");
+    assert_eq!(PointField::describe(), "enum PointField");
+    assert_ne!(PointField::x, PointField::y);
+    let builder = ConfigBuilder::default();
+    assert!(builder.name.is_none() && builder.size.is_none());
+    assert_eq!(ConfigBuilder::describe(), "struct ConfigBuilder");
+    assert_eq!(ConfigBuilderField::describe(), "enum ConfigBuilderField");
+    assert_eq!(LimitedBuilder::describe(), "struct LimitedBuilder");
+    assert!(LimitedBuilder::default().value.is_none());
+}
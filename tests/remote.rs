@@ -0,0 +1,93 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::macro_attr_remote;
+use std::cmp::Ordering;
+
+mod remote {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+}
+
+use remote::Point;
+
+trait Variants: Sized + 'static {
+    const VARIANTS: &'static [Self];
+}
+
+trait Fields {
+    const FIELDS: &'static [&'static str];
+}
+
+macro_rules! Variants {
+    (() $vis:vis enum $name:ident { $($variant:ident $(= $discr:expr)?),* $(,)? }) => {
+        impl Variants for $name {
+            const VARIANTS: &'static [$name] = &[$($name::$variant),*];
+        }
+    };
+}
+
+macro_rules! Fields {
+    (
+        @attrs [$(#[$($attrs:tt)*])*] ()
+        $vis:vis struct $name:ident { $($(#[$($field_attrs:tt)*])* $field_vis:vis $field:ident: $ty:ty),* $(,)? }
+    ) => {
+        impl Fields for $name {
+            const FIELDS: &'static [&'static str] = &[$(stringify!($field)),*];
+        }
+    };
+}
+
+macro_rules! Mirror {
+    (
+        @recurse $macro_attr:path { $($state:tt)* } ()
+        $vis:vis struct $name:ident { $($(#[$($field_attrs:tt)*])* $field_vis:vis $field:ident: $ty:ty),* $(,)? }
+    ) => {
+        $macro_attr! {
+            $($state)*
+            #[derive(Debug, @attrs Fields!)]
+            pub struct Mirror { $($field_vis $field: $ty),* }
+        }
+    };
+}
+
+macro_attr_remote! {
+    #[derive(Debug, Clone, Copy, Variants!)]
+    #[repr(i8)]
+    pub enum Ordering {
+        Less = -1,
+        Equal = 0,
+        Greater = 1,
+    }
+
+    #[derive(PartialEq, @attrs Fields!, @recurse Mirror!)]
+    pub struct Point {
+        #[Fields!(rename)]
+        pub x: i32,
+        pub y: i32,
+    }
+
+    struct NoMacroDerives;
+
+    #[derive(Clone)]
+    struct OnlyStdDerives;
+}
+
+#[test]
+fn test_remote() {
+    assert_eq!(Ordering::VARIANTS, &[Ordering::Less, Ordering::Equal, Ordering::Greater]);
+    assert_eq!(Point::FIELDS, &["x", "y"]);
+    assert_eq!(Point { x: 1, y: 2 }, Point { x: 1, y: 2 });
+    assert_eq!(Mirror::FIELDS, &["x", "y"]);
+    assert_eq!(format!("{:?}", Mirror { x: 1, y: 2 }), "Mirror { x: 1, y: 2 }");
+}
@@ -0,0 +1,95 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::macro_attr;
+
+trait Duplicate {
+    fn duplicate(&self) -> (Self, &'static str) where Self: Sized;
+}
+
+macro_rules! Duplicate {
+    (@derives [$($std:tt)*] [$($macros:tt)*] () $vis:vis $kw:ident $name:ident $($tail:tt)+) => {
+        Duplicate! { @find_copy $name $($std)* }
+    };
+    (@find_copy $name:ident Copy $($std:tt)*) => {
+        impl Duplicate for $name {
+            fn duplicate(&self) -> (Self, &'static str) { (*self, "copy") }
+        }
+    };
+    (@find_copy $name:ident #[cfg($cond:meta)] Copy $($std:tt)*) => {
+        #[cfg($cond)]
+        impl Duplicate for $name {
+            fn duplicate(&self) -> (Self, &'static str) { (*self, "copy") }
+        }
+        #[cfg(not($cond))]
+        impl Duplicate for $name {
+            fn duplicate(&self) -> (Self, &'static str) { (self.clone(), "clone") }
+        }
+    };
+    (@find_copy $name:ident $derive:tt $($std:tt)*) => {
+        Duplicate! { @find_copy $name $($std)* }
+    };
+    (@find_copy $name:ident) => {
+        impl Duplicate for $name {
+            fn duplicate(&self) -> (Self, &'static str) { (self.clone(), "clone") }
+        }
+    };
+}
+
+macro_rules! Siblings {
+    (
+        @derives [$($(#[$std_cfg:meta])? $std:path),*] [$($(#[$macro_cfg:meta])? $macros:ident !),*]
+        () $vis:vis $kw:ident $name:ident $($tail:tt)+
+    ) => {
+        impl $name {
+            fn siblings() -> (&'static [&'static str], &'static [&'static str]) {
+                (&[$(concat!($(stringify!($std_cfg), " ",)? stringify!($std))),*], &[$(concat!($(stringify!($macro_cfg), " ",)? stringify!($macros))),*])
+            }
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(Clone, Copy, @derives Duplicate!)]
+    struct Copied(u8);
+}
+
+macro_attr! {
+    #[derive(Clone, @derives Duplicate!, Debug)]
+    #[cfg_attr(all(), derive(Copy))]
+    struct Cloned(u8);
+}
+
+macro_attr! {
+    #[derive(Debug, @derives Siblings!, @derives Duplicate!)]
+    #[derive(::core::clone::Clone, PartialEq)]
+    #[cfg_attr(all(), derive(Eq))]
+    #[derive(PartialOrd if all(), Ord if all())]
+    struct Listed;
+
+    #[derive(Clone, @derives Duplicate!, Copy if any())]
+    #[cfg_attr(all(), derive(Debug, Hash if any(), @derives Siblings! if all()))]
+    struct Conditional(u8);
+}
+
+#[test]
+fn test_sibling_derives() {
+    assert_eq!(Copied(1).duplicate().1, "copy");
+    assert_eq!(Cloned(1).duplicate().1, "copy");
+    assert_eq!(Conditional(1).duplicate().1, "clone");
+    assert_eq!(Conditional::siblings(), (
+        &["Clone", "cfg(all(any())) Copy", "cfg(all()) Debug", "cfg(all(all(), any())) Hash"][..],
+        &["Duplicate", "cfg(all(all(), all())) Siblings"][..],
+    ));
+    assert_eq!(Listed.duplicate().1, "clone");
+    let (std, macros) = Listed::siblings();
+    assert_eq!(std, &["Debug", ":: core :: clone :: Clone", "PartialEq", "cfg(all()) Eq", "cfg(all(all())) PartialOrd", "cfg(all(all())) Ord"]);
+    assert_eq!(macros, &["Siblings", "Duplicate"]);
+}
@@ -0,0 +1,106 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+#![deny(warnings)]
+
+use macro_attr_2018::{macro_attr, split_generics};
+
+trait TypeName {
+    fn type_name() -> &'static str;
+}
+
+macro_rules! TypeName {
+    (
+        @parsed {
+            kind: $kind:ident,
+            vis: [$vis:vis],
+            name: $name:ident,
+            generics: [$($params:tt)*],
+            where: [$($where:tt)*],
+            $($body:tt)*
+        }
+        () $($item:tt)*
+    ) => {
+        split_generics! { TypeName! { @impl $name } [$($params)*] [$($where)*] }
+    };
+    (@impl $name:ident [$($impl_params:tt)*] [$($ty_params:tt)*] [$($where:tt)*]) => {
+        impl<$($impl_params)*> TypeName for $name<$($ty_params)*> where $($where)* {
+            fn type_name() -> &'static str { stringify!($name) }
+        }
+    };
+}
+
+macro_rules! Generics {
+    ([$($impl_params:tt)*] [$($ty_params:tt)*] [$($where:tt)*]) => {
+        [stringify!($($impl_params)*), stringify!($($ty_params)*), stringify!($($where)*)]
+    };
+}
+
+macro_attr! {
+    #[derive(@parsed TypeName!)]
+    struct Plain;
+}
+
+macro_attr! {
+    #[derive(@parsed TypeName!)]
+    struct Borrowed<'a, 'b: 'a, T: ?Sized + 'b, U = Vec<u8>> where U: Clone {
+        a: &'a &'b T,
+        u: U,
+    }
+}
+
+macro_attr! {
+    #[derive(@parsed TypeName!)]
+    enum Array<T: Into<Vec<Vec<u8>>>= Vec<Vec<u8>>, const N: usize = 3> {
+        Items([T; N]),
+    }
+}
+
+trait Assoc {
+    type A;
+}
+
+impl Assoc for u8 {
+    type A = u16;
+}
+
+macro_attr! {
+    #[derive(@parsed TypeName!)]
+    struct Projected<T: Into<<u8 as Assoc>::A> = u8, U = Vec<<u8 as Assoc>::A>>(T, U);
+}
+
+fn strip(s: &str) -> String {
+    s.split_whitespace().collect()
+}
+
+#[test]
+fn test_split_generics_impl() {
+    assert_eq!(Plain::type_name(), "Plain");
+    assert_eq!(<Borrowed<str>>::type_name(), "Borrowed");
+    assert_eq!(<Array<Vec<Vec<u8>>, 2>>::type_name(), "Array");
+    assert_eq!(<Projected>::type_name(), "Projected");
+    let Projected(t, u) = Projected(1u8, vec![2u16]);
+    assert_eq!((t, u), (1, vec![2]));
+    let b = Borrowed { a: &"x", u: vec![1] };
+    assert_eq!(b.u, vec![1]);
+    let _ = b.a;
+    let Array::Items(_) = Array::<Vec<Vec<u8>>>::Items(Default::default());
+}
+
+#[test]
+fn test_split_generics_parts() {
+    let [impl_params, ty_params, where_predicates] = split_generics! {
+        Generics! {} ['a, T: ?Sized + Fn(u8) -> u8 = fn(u8) -> u8, U: Iterator<Item = (u8, u16)>, const N: usize = { 1 + 2 }]
+        [T: 'a]
+    };
+    assert_eq!(strip(impl_params), "'a,T:?Sized+Fn(u8)->u8,U:Iterator<Item=(u8,u16)>,constN:usize,");
+    assert_eq!(strip(ty_params), "'a,T,U,N,");
+    assert_eq!(strip(where_predicates), "T:'a");
+    let [impl_params, ty_params, where_predicates] = split_generics! { Generics! {} [] };
+    assert_eq!((impl_params, ty_params, where_predicates), ("", "", ""));
+}
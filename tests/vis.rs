@@ -211,4 +211,89 @@ fn test_nested_vis_tt() {
     assert_eq!("", NestedPrivTTS::print_vis());
     assert_eq!("pub", NestedPubTTS::print_vis());
     assert_eq!("pub(crate)", NestedCrateTTS::print_vis());
-}
\ No newline at end of file
+}
+
+macro_rules! VisMatrixTT {
+    (() pub ($($vis:tt)+) $kind:ident $($it:tt)+) => {
+        pub const VIS_TT: &str = stringify!(pub ($($vis)+));
+    };
+    (() pub $kind:ident $($it:tt)+) => {
+        pub const VIS_TT: &str = stringify!(pub);
+    };
+    (() $kind:ident $($it:tt)+) => {
+        pub const VIS_TT: &str = "";
+    };
+}
+
+macro_rules! VisMatrix {
+    (() $vis:vis $kind:ident $($it:tt)+) => {
+        pub const VIS: &str = stringify!($vis);
+    };
+}
+
+macro_rules! vis_case {
+    ($case:ident [$($vis:tt)*] $($item:tt)+) => {
+        #[allow(dead_code)]
+        pub mod $case {
+            macro_attr_2018::macro_attr! {
+                #[derive(VisMatrixTT!, VisMatrix!)]
+                $($vis)* $($item)+
+            }
+        }
+    };
+}
+
+macro_rules! vis_cases {
+    ($kind:ident { $($item:tt)+ }) => {
+        mod $kind {
+            vis_case! { private [] $($item)+ }
+            vis_case! { public [pub] $($item)+ }
+            vis_case! { krate [pub(crate)] $($item)+ }
+            vis_case! { this [pub(self)] $($item)+ }
+            vis_case! { parent [pub(super)] $($item)+ }
+            vis_case! { path [pub(in crate::$kind)] $($item)+ }
+        }
+    };
+}
+
+vis_cases! { enums { enum Item { A } } }
+vis_cases! { structs { struct Item { a: u8 } } }
+vis_cases! { tuple_structs { struct Item(u8); } }
+vis_cases! { unions { union Item { a: u8 } } }
+vis_cases! { traits { trait Item { } } }
+vis_cases! { fns { fn item() { } } }
+vis_cases! { type_aliases { type Item = u8; } }
+vis_cases! { consts { const ITEM: u8 = 0; } }
+vis_cases! { statics { static ITEM: u8 = 0; } }
+
+fn squash(s: &str) -> String {
+    s.split_whitespace().collect()
+}
+
+macro_rules! assert_vis_cases {
+    ($($kind:ident),*) => {
+        $(
+            for (actual, expected) in [
+                (squash($kind::private::VIS_TT), ""),
+                (squash($kind::public::VIS_TT), "pub"),
+                (squash($kind::krate::VIS_TT), "pub(crate)"),
+                (squash($kind::this::VIS_TT), "pub(self)"),
+                (squash($kind::parent::VIS_TT), "pub(super)"),
+                (squash($kind::path::VIS_TT), concat!("pub(incrate::", stringify!($kind), ")")),
+                (squash($kind::private::VIS), ""),
+                (squash($kind::public::VIS), "pub"),
+                (squash($kind::krate::VIS), "pub(crate)"),
+                (squash($kind::this::VIS), "pub(self)"),
+                (squash($kind::parent::VIS), "pub(super)"),
+                (squash($kind::path::VIS), concat!("pub(incrate::", stringify!($kind), ")")),
+            ] {
+                assert_eq!(expected, actual, "{}", stringify!($kind));
+            }
+        )*
+    };
+}
+
+#[test]
+fn test_vis_matrix() {
+    assert_vis_cases!(enums, structs, tuple_structs, unions, traits, fns, type_aliases, consts, statics);
+}
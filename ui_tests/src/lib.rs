@@ -0,0 +1,12 @@
+// Copyright (c) 2026 Warlock <internalmike@gmail.com>.
+//
+// Licensed under the MIT license (see LICENSE or <http://opensource.org
+// /licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+// <http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+// files in the project carrying such notice may not be copied, modified,
+// or distributed except according to those terms.
+
+//! Compile-fail tests for `macro-attr-2018`.
+//!
+//! The expected compiler output depends on the `rustc` version,
+//! so these tests live outside of the main crate and run on the stable toolchain only.
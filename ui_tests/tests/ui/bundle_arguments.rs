@@ -0,0 +1,17 @@
+use macro_attr_2018::{macro_attr, macro_attr_bundle};
+
+macro_attr_bundle! {
+    Ordinal = PartialEq, Eq, PartialOrd, Ord;
+}
+
+macro_attr! {
+    #[derive(@bundle Ordinal!(u8))]
+    struct Args;
+}
+
+macro_attr! {
+    #[derive(Ordinal!)]
+    struct Unmarked;
+}
+
+fn main() { }
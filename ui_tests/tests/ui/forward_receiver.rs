@@ -0,0 +1,10 @@
+use macro_attr_2018::{macro_attr, Forward};
+
+macro_attr! {
+    #[derive(Forward!(&, &mut))]
+    trait Counter {
+        fn increment(&mut self);
+    }
+}
+
+fn main() { }
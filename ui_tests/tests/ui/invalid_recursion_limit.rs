@@ -0,0 +1,8 @@
+use macro_attr_2018::macro_attr;
+
+macro_attr! {
+    #[derive(@recurse(100) Name!)]
+    struct Foo;
+}
+
+fn main() { }
@@ -0,0 +1,17 @@
+use macro_attr_2018::macro_attr_args;
+
+macro_attr_args! {
+    Args { name = [], verbose = [false] }
+}
+
+macro_rules! Callback {
+    ($($args:tt)*) => { };
+}
+
+Args! { Callback! {} (verbose, nmae = Foo) }
+
+Args! { Callback! {} (name =, verbose) }
+
+Args! { Callback! {} (name: Foo) }
+
+fn main() { }
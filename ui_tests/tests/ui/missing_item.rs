@@ -0,0 +1,7 @@
+use macro_attr_2018::macro_attr;
+
+macro_attr! {
+    #[derive(Clone, Name!)]
+}
+
+fn main() { }
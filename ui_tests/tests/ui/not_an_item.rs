@@ -0,0 +1,9 @@
+use macro_attr_2018::macro_attr;
+
+macro_attr! {
+    #[derive(Name!)]
+    #[repr(C)]
+    [u8; 3];
+}
+
+fn main() { }
@@ -0,0 +1,20 @@
+use macro_attr_2018::macro_attr;
+
+macro_rules! Again {
+    (@recurse $macro_attr:path { $($state:tt)* } () $vis:vis struct $name:ident;) => {
+        mod nested {
+            $macro_attr! {
+                $($state)*
+                #[derive(@recurse Again!)]
+                struct Nested;
+            }
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(@recurse(2) Again!)]
+    struct Foo;
+}
+
+fn main() { }
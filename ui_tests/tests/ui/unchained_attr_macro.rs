@@ -0,0 +1,9 @@
+use macro_attr_2018::macro_attr;
+
+macro_attr! {
+    #[First!(a)]
+    #[@chain Second!(b)]
+    struct Foo;
+}
+
+fn main() { }
@@ -0,0 +1,13 @@
+use macro_attr_2018::macro_attr;
+
+macro_rules! Declared {
+    (@macro_attr v2 { $($keys:tt)* } $($item:tt)*) => { };
+    ($($v1:tt)*) => { __macro_attr_v2! { } };
+}
+
+macro_attr! {
+    #[derive(@attrs Declared!)]
+    struct Foo;
+}
+
+fn main() { }